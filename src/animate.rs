@@ -0,0 +1,264 @@
+use std::io::{self, BufRead, Write};
+use std::thread;
+use std::time::Duration;
+
+use colored::{Color, Colorize};
+
+use crate::days::{day11, day13, day20, day25, day6};
+use crate::grid::{Grid, Pos};
+use crate::ParseError;
+
+/// One character of a frame, and the color to draw it in, if any.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub symbol: char,
+    pub color: Option<Color>,
+}
+
+impl Cell {
+    pub const fn plain(symbol: char) -> Cell {
+        Cell {
+            symbol,
+            color: None,
+        }
+    }
+
+    pub const fn colored(symbol: char, color: Color) -> Cell {
+        Cell {
+            symbol,
+            color: Some(color),
+        }
+    }
+}
+
+/// A picture of a simulation at one step, with a caption saying which.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub caption: String,
+    pub cells: Grid<Cell>,
+}
+
+impl Frame {
+    /// Draws the caption, then the cells row by row, cut down to the middle
+    /// `width` by `height` cells if they don't fit, as simulations tend to
+    /// grow outwards.
+    pub fn render(&self, width: usize, height: usize) -> String {
+        let (shown_width, shown_height) = (
+            width.min(self.cells.width()),
+            height.min(self.cells.height()),
+        );
+        let left = (self.cells.width() - shown_width) / 2;
+        let top = (self.cells.height() - shown_height) / 2;
+
+        let mut text = self.caption.clone();
+        if (shown_width, shown_height) != (self.cells.width(), self.cells.height()) {
+            text += &format!(
+                " (the middle {}x{} of {}x{})",
+                shown_width,
+                shown_height,
+                self.cells.width(),
+                self.cells.height()
+            );
+        }
+        text.push('\n');
+
+        for y in top..top + shown_height {
+            // cells of the same color are colored together, which keeps the
+            // escape codes down to a few per row
+            let mut run = String::new();
+            let mut run_color = None;
+            for x in left..left + shown_width {
+                let cell = self.cells[Pos::new(x, y)];
+                if cell.color != run_color {
+                    text += &paint(&run, run_color);
+                    run.clear();
+                    run_color = cell.color;
+                }
+                run.push(cell.symbol);
+            }
+            text += &paint(&run, run_color);
+            text.push('\n');
+        }
+
+        text
+    }
+}
+
+fn paint(text: &str, color: Option<Color>) -> String {
+    match color {
+        Some(color) if !text.is_empty() => text.color(color).to_string(),
+        _ => String::from(text),
+    }
+}
+
+/// A simulation's frames, made as they're asked for.
+pub type Frames = Box<dyn Iterator<Item = Frame>>;
+
+/// The frames a day's simulation goes through, for days that have one worth
+/// watching.
+#[derive(Debug, Clone, Copy)]
+pub struct Animation {
+    pub day: u8,
+    frames: fn(&str) -> Result<Frames, ParseError>,
+}
+
+impl Animation {
+    /// Parses `input` and starts the simulation on it.
+    pub fn frames(&self, input: &str) -> Result<Frames, ParseError> {
+        (self.frames)(input)
+    }
+}
+
+pub const ALL: &[Animation] = &[
+    Animation {
+        day: 6,
+        frames: day6::frames,
+    },
+    Animation {
+        day: 11,
+        frames: day11::frames,
+    },
+    Animation {
+        day: 13,
+        frames: day13::frames,
+    },
+    Animation {
+        day: 20,
+        frames: day20::frames,
+    },
+    Animation {
+        day: 25,
+        frames: day25::frames,
+    },
+];
+
+pub fn get(day: u8) -> Option<&'static Animation> {
+    ALL.iter().find(|animation| animation.day == day)
+}
+
+/// Shows frames one after another on a terminal.
+#[derive(Debug, Clone)]
+pub struct Player {
+    pub frame_rate: u32,
+    /// Wait for enter before each frame after the first, instead of going at
+    /// the frame rate.
+    pub stepping: bool,
+    /// Stop after this many frames, as some simulations never end.
+    pub max_frames: Option<usize>,
+    /// How many columns and rows of cells fit on the terminal.
+    pub size: (usize, usize),
+}
+
+impl Player {
+    /// Clears `out` and draws each frame over the last, reading from
+    /// `controls` between frames when stepping, where `q` stops early.
+    /// Returns how many frames were shown.
+    pub fn play(
+        &self,
+        frames: impl Iterator<Item = Frame>,
+        out: &mut impl Write,
+        controls: &mut impl BufRead,
+    ) -> io::Result<usize> {
+        let (width, height) = self.size;
+        let delay = Duration::from_secs(1) / self.frame_rate.max(1);
+        let mut shown = 0;
+
+        for frame in frames.take(self.max_frames.unwrap_or(usize::MAX)) {
+            if shown > 0 {
+                if self.stepping {
+                    write!(out, "enter for the next step, q to stop: ")?;
+                    out.flush()?;
+                    let mut line = String::new();
+                    if controls.read_line(&mut line)? == 0 || line.trim() == "q" {
+                        break;
+                    }
+                } else {
+                    thread::sleep(delay);
+                }
+            }
+
+            // home the cursor and clear the screen, then draw the frame
+            write!(out, "\x1b[H\x1b[2J{}", frame.render(width, height))?;
+            out.flush()?;
+            shown += 1;
+        }
+
+        Ok(shown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered(count: usize) -> impl Iterator<Item = Frame> {
+        (0..count).map(|i| Frame {
+            caption: format!("frame {}", i),
+            cells: Grid::new(3, 2, Cell::plain('#')),
+        })
+    }
+
+    fn player() -> Player {
+        Player {
+            frame_rate: 1000,
+            stepping: false,
+            max_frames: None,
+            size: (80, 24),
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let frame = Frame {
+            caption: String::from("step 1"),
+            cells: Grid::from_fn(3, 2, |pos| {
+                Cell::plain(if pos.x == pos.y { '#' } else { '.' })
+            }),
+        };
+
+        assert_eq!(frame.render(80, 24), "step 1\n#..\n.#.\n");
+        assert_eq!(frame.render(1, 1), "step 1 (the middle 1x1 of 3x2)\n.\n");
+    }
+
+    #[test]
+    fn test_play() {
+        let mut out = Vec::new();
+
+        let shown = player()
+            .play(numbered(3), &mut out, &mut io::empty())
+            .unwrap();
+
+        assert_eq!(shown, 3);
+        assert!(String::from_utf8(out)
+            .unwrap()
+            .contains("frame 2\n###\n###\n"));
+    }
+
+    #[test]
+    fn test_play_max_frames() {
+        let player = Player {
+            max_frames: Some(2),
+            ..player()
+        };
+
+        let shown = player
+            .play(numbered(5), &mut Vec::new(), &mut io::empty())
+            .unwrap();
+
+        assert_eq!(shown, 2);
+    }
+
+    #[test]
+    fn test_play_stepping() {
+        let player = Player {
+            stepping: true,
+            ..player()
+        };
+
+        let shown = player
+            .play(numbered(5), &mut Vec::new(), &mut "\n\nq\n".as_bytes())
+            .unwrap();
+
+        assert_eq!(shown, 3);
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::{parse, ParseError, Part};
+
+/// The registry of known answers checked in alongside the solutions.
+pub const KNOWN: &str = include_str!("../answers.txt");
+
+/// Known answers, keyed by day, part and the name of the input they are for.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part, String), String>,
+}
+
+impl Answers {
+    /// Reads an answers file: one `<day> <part> <input> <answer>` per line,
+    /// with `\n` standing in for newlines in the answer. Blank lines and lines
+    /// starting with `#` are skipped.
+    pub fn parse(input: &str) -> Result<Answers, ParseError> {
+        let mut answers = Answers::default();
+
+        for line in input.lines() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut split = line.splitn(4, ' ');
+            let day = parse::next(input, line, &mut split, "a day")?;
+            let day = parse::field(input, day, "a day")?;
+            let part = parse::next(input, line, &mut split, "a part")?;
+            let part = parse::field(input, part, "a part")
+                .ok()
+                .and_then(Part::from_number)
+                .ok_or_else(|| ParseError::at(input, part, "part 1 or 2"))?;
+            let name = parse::next(input, line, &mut split, "an input name")?;
+            let answer = parse::next(input, line, &mut split, "an answer")?;
+
+            if answers.get(day, part, name).is_some() {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "only one answer per day, part and input",
+                ));
+            }
+            answers.insert(day, part, name, &answer.replace("\\n", "\n"));
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, part: Part, name: &str) -> Option<&str> {
+        self.answers
+            .get(&(day, part, String::from(name)))
+            .map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, name: &str, answer: &str) {
+        self.answers
+            .insert((day, part, String::from(name)), String::from(answer));
+    }
+
+    /// Every answer as `(day, part, input name, answer)`, in that order.
+    pub fn iter(&self) -> impl Iterator<Item = (u8, Part, &str, &str)> {
+        self.answers
+            .iter()
+            .map(|((day, part, name), answer)| (*day, *part, name.as_str(), answer.as_str()))
+    }
+
+    /// Compares an answer given by a solver with the known one.
+    pub fn check(&self, day: u8, part: Part, name: &str, answer: &str) -> Verdict {
+        match self.get(day, part, name) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: String::from(expected),
+            },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+/// How an answer compares with the known one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// There is no known answer to compare with.
+    Missing,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { .. } => write!(f, "FAIL"),
+            Verdict::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "# day part input answer
+
+1 1 embedded 1451
+1 1 alice 1500
+13 2 embedded \\n#..#\\n.##.
+",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(1, Part::One, "embedded"), Some("1451"));
+        assert_eq!(answers.get(1, Part::One, "alice"), Some("1500"));
+        assert_eq!(answers.get(1, Part::Two, "embedded"), None);
+        assert_eq!(answers.get(13, Part::Two, "embedded"), Some("\n#..#\n.##."));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Answers::parse("1 3 embedded 1451").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+
+        let err = Answers::parse("1 1 embedded").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 13));
+        assert_eq!(err.expected, "an answer");
+
+        let err = Answers::parse("1 1 embedded 1451\n1 1 embedded 1452").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("1 1 embedded 1451").unwrap();
+
+        assert_eq!(
+            answers.check(1, Part::One, "embedded", "1451"),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check(1, Part::One, "embedded", "1450"),
+            Verdict::Fail {
+                expected: String::from("1451")
+            }
+        );
+        assert_eq!(
+            answers.check(1, Part::One, "alice", "1451"),
+            Verdict::Missing
+        );
+    }
+
+    #[test]
+    fn test_known_answers_are_for_real_days() {
+        let answers = Answers::parse(KNOWN).unwrap();
+
+        for (day, _, _) in answers.answers.keys() {
+            assert!(days::get(*day).is_some(), "day {} has no solution", day);
+        }
+    }
+}
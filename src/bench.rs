@@ -0,0 +1,159 @@
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{Day, ParseError, Part};
+
+/// A step of solving a day that gets timed on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [
+        Stage::Parse,
+        Stage::Solve(Part::One),
+        Stage::Solve(Part::Two),
+    ];
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Solve(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+/// How long each run of a stage took, fastest first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timings {
+    samples: Vec<Duration>,
+}
+
+impl Timings {
+    /// Times `iterations` runs of `f`, which must be at least one.
+    pub fn measure<T>(iterations: usize, mut f: impl FnMut() -> T) -> Timings {
+        assert!(iterations > 0, "need at least one iteration to time");
+
+        let mut samples: Vec<_> = (0..iterations)
+            .map(|_| {
+                let start = Instant::now();
+                black_box(f());
+                start.elapsed()
+            })
+            .collect();
+        samples.sort();
+
+        Timings { samples }
+    }
+
+    pub fn iterations(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        self.samples[self.samples.len() / 2]
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+}
+
+/// The timings of every stage of one day.
+#[derive(Debug, Clone)]
+pub struct DayBench {
+    pub day: u8,
+    pub stages: Vec<(Stage, Timings)>,
+}
+
+impl DayBench {
+    /// Times parsing `input` and answering each part `iterations` times.
+    pub fn run(day: &Day, input: &str, iterations: usize) -> Result<DayBench, ParseError> {
+        let parsed = day.parse(input)?;
+        let stages = Stage::ALL
+            .iter()
+            .map(|stage| {
+                let timings = match stage {
+                    Stage::Parse => Timings::measure(iterations, || day.parse(input)),
+                    Stage::Solve(part) => {
+                        Timings::measure(iterations, || day.solve(*part, &parsed))
+                    }
+                };
+                (*stage, timings)
+            })
+            .collect();
+
+        Ok(DayBench {
+            day: day.number,
+            stages,
+        })
+    }
+}
+
+/// The header of the results file written by [`write_results`].
+pub const RESULTS_HEADER: &str = "day,stage,iterations,min_ns,median_ns,max_ns";
+
+/// Renders benchmark results as CSV, one line per day and stage, so runs
+/// from different commits can be diffed or loaded into a spreadsheet.
+pub fn write_results(benches: &[DayBench]) -> String {
+    let mut out = format!("{}\n", RESULTS_HEADER);
+
+    for bench in benches {
+        for (stage, timings) in &bench.stages {
+            out += &format!(
+                "{},{},{},{},{},{}\n",
+                bench.day,
+                stage,
+                timings.iterations(),
+                timings.min().as_nanos(),
+                timings.median().as_nanos(),
+                timings.max().as_nanos(),
+            );
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_timings() {
+        let mut sleep = 3;
+        let timings = Timings::measure(3, || {
+            std::thread::sleep(Duration::from_millis(sleep));
+            sleep -= 1;
+        });
+
+        assert_eq!(timings.iterations(), 3);
+        assert!(timings.min() <= timings.median());
+        assert!(timings.median() <= timings.max());
+        assert!(timings.max() >= Duration::from_millis(3));
+    }
+
+    #[test]
+    fn test_write_results() {
+        let day = days::get(1).unwrap();
+        let bench = DayBench::run(day, day.input, 2).unwrap();
+        let results = write_results(&[bench]);
+        let lines: Vec<_> = results.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], RESULTS_HEADER);
+        assert!(lines[1].starts_with("1,parse,2,"));
+        assert!(lines[2].starts_with("1,part1,2,"));
+        assert!(lines[3].starts_with("1,part2,2,"));
+    }
+}
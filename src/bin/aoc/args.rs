@@ -0,0 +1,483 @@
+use std::path::PathBuf;
+
+use aoc2021::animate::{self, Animation};
+use aoc2021::export::{self, Export};
+use aoc2021::generate::{self, Generator};
+use aoc2021::input::Source;
+use aoc2021::report::Format;
+use aoc2021::trace::Filter;
+use aoc2021::{days, Day, Part};
+
+pub const USAGE: &str = "usage:
+    aoc run <day|all> [--part <1|2>] [--input <file|dir|->] [--format <text|json|csv>]
+            [--jobs <n>]
+    aoc verify <day|all> [--input <file|dir|->] [--answers <file>]
+    aoc bench <day|all> [--iterations <n>] [--input <file|dir|->] [--output <file>]
+    aoc new <day>
+    aoc generate <day> [--seed <n>] [--size <n>]
+    aoc fuzz <day|all> [--iterations <n>] [--seed <n>]
+    aoc animate <day> [--input <file|dir|->] [--fps <n>] [--step] [--frames <n>]
+    aoc export <day> [--input <file|dir|->] [--format <pbm|ppm|svg>] [--output <dir>]
+    aoc repl <day> [--input <file|dir>]
+    aoc check <day> <file|dir|->
+    aoc sweep [--window <n>] [--input <file|dir|->]
+
+options:
+    --trace <filter>    log solver diagnostics to stderr, e.g. `debug`,
+                        `day4` or `day4=debug,day15`";
+
+#[derive(Debug)]
+pub enum Command {
+    Run {
+        days: Vec<Day>,
+        parts: Vec<Part>,
+        source: Source,
+        format: Format,
+        /// How many days to solve at once.
+        jobs: usize,
+    },
+    Verify {
+        days: Vec<Day>,
+        source: Source,
+        /// Where to read known answers from instead of the checked in ones.
+        answers: Option<PathBuf>,
+    },
+    Bench {
+        days: Vec<Day>,
+        iterations: usize,
+        source: Source,
+        /// Where to write the results as CSV, if anywhere.
+        output: Option<PathBuf>,
+    },
+    New {
+        day: u8,
+    },
+    Generate {
+        generator: Generator,
+        /// The seed to generate from, or `None` for a different one each run.
+        seed: Option<u64>,
+        /// How big an input to generate, or `None` for one the size of the
+        /// puzzle's own.
+        size: Option<usize>,
+    },
+    Fuzz {
+        days: Vec<Day>,
+        /// How many mutated inputs to parse for each day.
+        iterations: usize,
+        /// The seed to mutate from, or `None` for a different one each run.
+        seed: Option<u64>,
+    },
+    Animate {
+        day: Day,
+        animation: Animation,
+        source: Source,
+        /// How many frames to show a second.
+        frame_rate: u32,
+        /// Wait for enter between frames instead.
+        stepping: bool,
+        max_frames: Option<usize>,
+    },
+    Export {
+        day: Day,
+        export: Export,
+        source: Source,
+        format: export::Format,
+        /// The directory to write the pictures into.
+        output: PathBuf,
+    },
+    Repl {
+        day: Day,
+        source: Source,
+    },
+    Check {
+        day: Day,
+        source: Source,
+    },
+    Sweep {
+        /// How many of day 1's depths to sum before comparing.
+        window: usize,
+        source: Source,
+    },
+}
+
+pub fn parse(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+
+    match args.next().map(String::as_str) {
+        Some("run") => {
+            let days = parse_days(args.next())?;
+            let mut parts = Part::BOTH.to_vec();
+            let mut source = Source::Embedded;
+            let mut format = Format::Text;
+            let mut jobs = 1;
+
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--part" => parts = vec![parse_part(args.next())?],
+                    "--format" => {
+                        format = args
+                            .next()
+                            .ok_or("missing format after `--format`")?
+                            .parse()?
+                    }
+                    "--input" => source = parse_source(args.next())?,
+                    "--jobs" => jobs = parse_jobs(args.next())?,
+                    other => return Err(format!("unexpected argument `{}`", other)),
+                }
+            }
+
+            check_source(&days, &source)?;
+
+            Ok(Command::Run {
+                days,
+                parts,
+                source,
+                format,
+                jobs,
+            })
+        }
+        Some("verify") => {
+            let days = parse_days(args.next())?;
+            let mut source = Source::Embedded;
+            let mut answers = None;
+
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--input" => source = parse_source(args.next())?,
+                    "--answers" => {
+                        let path = args.next().ok_or("missing path after `--answers`")?;
+                        answers = Some(PathBuf::from(path));
+                    }
+                    other => return Err(format!("unexpected argument `{}`", other)),
+                }
+            }
+
+            check_source(&days, &source)?;
+
+            Ok(Command::Verify {
+                days,
+                source,
+                answers,
+            })
+        }
+        Some("bench") => {
+            let days = parse_days(args.next())?;
+            let mut iterations = 10;
+            let mut source = Source::Embedded;
+            let mut output = None;
+
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--iterations" => iterations = parse_iterations(args.next())?,
+                    "--input" => source = parse_source(args.next())?,
+                    "--output" => {
+                        let path = args.next().ok_or("missing path after `--output`")?;
+                        output = Some(PathBuf::from(path));
+                    }
+                    other => return Err(format!("unexpected argument `{}`", other)),
+                }
+            }
+
+            check_source(&days, &source)?;
+
+            Ok(Command::Bench {
+                days,
+                iterations,
+                source,
+                output,
+            })
+        }
+        Some("new") => {
+            let arg = args.next().ok_or("missing day")?;
+            let day = arg
+                .parse()
+                .ok()
+                .filter(|day| (1..=25).contains(day))
+                .ok_or(format!("`{}` is not a day of the calendar", arg))?;
+
+            if let Some(other) = args.next() {
+                return Err(format!("unexpected argument `{}`", other));
+            }
+
+            Ok(Command::New { day })
+        }
+        Some("generate") => {
+            let arg = args.next().ok_or("missing day")?;
+            let generator = arg
+                .parse()
+                .ok()
+                .and_then(generate::get)
+                .ok_or(format!("`{}` is not a day there's a generator for", arg))?;
+            let mut seed = None;
+            let mut size = None;
+
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--seed" => seed = Some(parse_seed(args.next())?),
+                    "--size" => size = Some(parse_size(args.next())?),
+                    other => return Err(format!("unexpected argument `{}`", other)),
+                }
+            }
+
+            Ok(Command::Generate {
+                generator: *generator,
+                seed,
+                size,
+            })
+        }
+        Some("fuzz") => {
+            let days = parse_days(args.next())?;
+            let mut iterations = 10_000;
+            let mut seed = None;
+
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--iterations" => iterations = parse_iterations(args.next())?,
+                    "--seed" => seed = Some(parse_seed(args.next())?),
+                    other => return Err(format!("unexpected argument `{}`", other)),
+                }
+            }
+
+            Ok(Command::Fuzz {
+                days,
+                iterations,
+                seed,
+            })
+        }
+        Some("animate") => {
+            let &[day] = parse_days(args.next())?.as_slice() else {
+                return Err(String::from("pick a single day to animate"));
+            };
+            let animation = *animate::get(day.number)
+                .ok_or(format!("day {} doesn't have an animation", day.number))?;
+            let mut source = Source::Embedded;
+            let mut frame_rate = 10;
+            let mut stepping = false;
+            let mut max_frames = None;
+
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--input" => source = parse_source(args.next())?,
+                    "--fps" => frame_rate = parse_frame_rate(args.next())?,
+                    "--step" => stepping = true,
+                    "--frames" => max_frames = Some(parse_frames(args.next())?),
+                    other => return Err(format!("unexpected argument `{}`", other)),
+                }
+            }
+
+            Ok(Command::Animate {
+                day,
+                animation,
+                source,
+                frame_rate,
+                stepping,
+                max_frames,
+            })
+        }
+        Some("export") => {
+            let &[day] = parse_days(args.next())?.as_slice() else {
+                return Err(String::from("pick a single day to export"));
+            };
+            let export = *export::get(day.number).ok_or(format!(
+                "day {} doesn't have anything to export",
+                day.number
+            ))?;
+            let mut source = Source::Embedded;
+            let mut format = export::Format::Svg;
+            let mut output = PathBuf::from(".");
+
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--input" => source = parse_source(args.next())?,
+                    "--format" => {
+                        format = args
+                            .next()
+                            .ok_or("missing format after `--format`")?
+                            .parse()?
+                    }
+                    "--output" => {
+                        let path = args.next().ok_or("missing path after `--output`")?;
+                        output = PathBuf::from(path);
+                    }
+                    other => return Err(format!("unexpected argument `{}`", other)),
+                }
+            }
+
+            Ok(Command::Export {
+                day,
+                export,
+                source,
+                format,
+                output,
+            })
+        }
+        Some("repl") => {
+            let &[day] = parse_days(args.next())?.as_slice() else {
+                return Err(String::from("pick a single day to explore"));
+            };
+            let mut source = Source::Embedded;
+
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--input" => source = parse_source(args.next())?,
+                    other => return Err(format!("unexpected argument `{}`", other)),
+                }
+            }
+
+            if source == Source::Stdin {
+                return Err(String::from(
+                    "the repl reads commands from stdin, pass the input as a file",
+                ));
+            }
+
+            Ok(Command::Repl { day, source })
+        }
+        Some("check") => {
+            let &[day] = parse_days(args.next())?.as_slice() else {
+                return Err(String::from("pick a single day to check"));
+            };
+            let source = Source::from_arg(args.next().ok_or("missing input to check")?);
+
+            if let Some(other) = args.next() {
+                return Err(format!("unexpected argument `{}`", other));
+            }
+
+            Ok(Command::Check { day, source })
+        }
+        Some("sweep") => {
+            let mut window = 3;
+            let mut source = Source::Embedded;
+
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--window" => window = parse_window(args.next())?,
+                    "--input" => source = parse_source(args.next())?,
+                    other => return Err(format!("unexpected argument `{}`", other)),
+                }
+            }
+
+            Ok(Command::Sweep { window, source })
+        }
+        Some(other) => Err(format!("unknown command `{}`", other)),
+        None => Err(String::from("missing command")),
+    }
+}
+
+/// Takes `--trace <filter>` out of `args`, wherever it is, as it applies to
+/// every command.
+pub fn take_trace(args: &mut Vec<String>) -> Result<Option<Filter>, String> {
+    let Some(index) = args.iter().position(|arg| arg == "--trace") else {
+        return Ok(None);
+    };
+
+    args.remove(index);
+    if index == args.len() {
+        return Err(String::from("missing filter after `--trace`"));
+    }
+
+    args.remove(index).parse().map(Some)
+}
+
+fn parse_days(arg: Option<&String>) -> Result<Vec<Day>, String> {
+    match arg.map(String::as_str) {
+        Some("all") => Ok(days::ALL.to_vec()),
+        Some(day) => {
+            let number: u8 = day
+                .parse()
+                .map_err(|_| format!("`{}` is not a day number", day))?;
+            let day = days::get(number).ok_or(format!("day {} isn't solved yet", number))?;
+            Ok(vec![*day])
+        }
+        None => Err(String::from("missing day")),
+    }
+}
+
+fn check_source(days: &[Day], source: &Source) -> Result<(), String> {
+    if days.len() > 1 && !source.covers_many_days() {
+        return Err(format!(
+            "{} only holds one day's input, pick a day or pass a directory",
+            source
+        ));
+    }
+
+    Ok(())
+}
+
+fn parse_part(arg: Option<&String>) -> Result<Part, String> {
+    let arg = arg.ok_or("missing part after `--part`")?;
+
+    arg.parse()
+        .ok()
+        .and_then(Part::from_number)
+        .ok_or(format!("`{}` is not a part, expected 1 or 2", arg))
+}
+
+fn parse_iterations(arg: Option<&String>) -> Result<usize, String> {
+    let arg = arg.ok_or("missing count after `--iterations`")?;
+
+    arg.parse()
+        .ok()
+        .filter(|iterations| *iterations > 0)
+        .ok_or(format!("`{}` is not a positive number of iterations", arg))
+}
+
+fn parse_jobs(arg: Option<&String>) -> Result<usize, String> {
+    let arg = arg.ok_or("missing count after `--jobs`")?;
+
+    arg.parse()
+        .ok()
+        .filter(|jobs| *jobs > 0)
+        .ok_or(format!("`{}` is not a positive number of jobs", arg))
+}
+
+fn parse_window(arg: Option<&String>) -> Result<usize, String> {
+    let arg = arg.ok_or("missing size after `--window`")?;
+
+    arg.parse()
+        .ok()
+        .filter(|window| *window > 0)
+        .ok_or(format!("`{}` is not a positive window size", arg))
+}
+
+fn parse_seed(arg: Option<&String>) -> Result<u64, String> {
+    let arg = arg.ok_or("missing number after `--seed`")?;
+
+    arg.parse()
+        .map_err(|_| format!("`{}` is not a seed, expected a whole number", arg))
+}
+
+fn parse_size(arg: Option<&String>) -> Result<usize, String> {
+    let arg = arg.ok_or("missing number after `--size`")?;
+
+    arg.parse()
+        .ok()
+        .filter(|size| *size > 0)
+        .ok_or(format!("`{}` is not a positive size", arg))
+}
+
+fn parse_frame_rate(arg: Option<&String>) -> Result<u32, String> {
+    let arg = arg.ok_or("missing count after `--fps`")?;
+
+    arg.parse()
+        .ok()
+        .filter(|frame_rate| *frame_rate > 0)
+        .ok_or(format!(
+            "`{}` is not a positive number of frames a second",
+            arg
+        ))
+}
+
+fn parse_frames(arg: Option<&String>) -> Result<usize, String> {
+    let arg = arg.ok_or("missing count after `--frames`")?;
+
+    arg.parse()
+        .ok()
+        .filter(|frames| *frames > 0)
+        .ok_or(format!("`{}` is not a positive number of frames", arg))
+}
+
+fn parse_source(arg: Option<&String>) -> Result<Source, String> {
+    let arg = arg.ok_or("missing path after `--input`")?;
+
+    Ok(Source::from_arg(arg))
+}
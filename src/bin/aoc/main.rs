@@ -0,0 +1,464 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::panic;
+use std::path::Path;
+use std::process;
+use std::time::{Duration, Instant, SystemTime};
+
+use aoc2021::animate::{Animation, Player};
+use aoc2021::answers::{self, Answers, Verdict};
+use aoc2021::bench::{self, DayBench};
+use aoc2021::check;
+use aoc2021::days::{self, day1, Day1};
+use aoc2021::export::{self, Export};
+use aoc2021::fuzz;
+use aoc2021::generate::{Generator, Rng};
+use aoc2021::input::Source;
+use aoc2021::pool;
+use aoc2021::repl::{self, Session};
+use aoc2021::report::{Format, Record, Report};
+use aoc2021::trace;
+use aoc2021::{Day, Part, Solution};
+
+mod args;
+mod scaffold;
+
+use args::Command;
+
+/// The answers to one day, and how long the thread that found them was busy
+/// reading, parsing and solving. That stands in for the cpu time the day
+/// took, though it also counts any time the thread spent waiting for a core
+/// when there are more jobs than cores.
+struct DayRun {
+    records: Vec<Record>,
+    busy: Duration,
+}
+
+fn run_day(day: &Day, parts: &[Part], source: &Source, name: &str) -> Result<DayRun, String> {
+    let start = Instant::now();
+    let input = source.load(day, |input| day.parse(input))?;
+    let mut records = Vec::new();
+
+    for part in parts {
+        let solve_start = Instant::now();
+        let answer = day.answer(*part, &input);
+        records.push(Record {
+            day: day.number,
+            part: *part,
+            answer: answer.text,
+            solved: answer.solved,
+            input: String::from(name),
+            elapsed: solve_start.elapsed(),
+        });
+    }
+
+    Ok(DayRun {
+        records,
+        busy: start.elapsed(),
+    })
+}
+
+fn run(
+    days: &[Day],
+    parts: &[Part],
+    source: &Source,
+    format: Format,
+    jobs: usize,
+) -> Result<(), String> {
+    let name = source.name();
+    let mut report = Report::new(format, io::stdout().lock());
+    let mut result = Ok(());
+    let mut busy = Duration::ZERO;
+    let start = Instant::now();
+
+    pool::run_ordered(
+        days,
+        jobs,
+        |day| run_day(day, parts, source, &name),
+        |day_run| {
+            if result.is_err() {
+                return;
+            }
+            result = day_run.and_then(|day_run| {
+                busy += day_run.busy;
+                day_run
+                    .records
+                    .iter()
+                    .try_for_each(|record| report.record(record))
+                    .map_err(|err| format!("couldn't write answers: {}", err))
+            });
+        },
+    );
+    let wall = start.elapsed();
+
+    // closed off even when a day failed, so what's there still parses
+    let finished = report
+        .finish()
+        .map(drop)
+        .map_err(|err| format!("couldn't write answers: {}", err));
+    result?;
+    finished?;
+
+    // on stderr, so it can't get mixed up with the answers
+    if days.len() > 1 {
+        eprintln!(
+            "{} days in {:.1?} of wall time, {:.1?} of cpu time across {} {}",
+            days.len(),
+            wall,
+            busy,
+            jobs,
+            if jobs == 1 { "job" } else { "jobs" }
+        );
+    }
+
+    Ok(())
+}
+
+fn load_answers(path: Option<&Path>) -> Result<Answers, String> {
+    let Some(path) = path else {
+        return Answers::parse(answers::KNOWN).map_err(|err| {
+            format!(
+                "couldn't parse the known answers\n{}",
+                err.annotate(answers::KNOWN)
+            )
+        });
+    };
+
+    let text = fs::read_to_string(path)
+        .map_err(|err| format!("couldn't read answers from {}: {}", path.display(), err))?;
+
+    Answers::parse(&text).map_err(|err| {
+        format!(
+            "couldn't parse answers from {}\n{}",
+            path.display(),
+            err.annotate(&text)
+        )
+    })
+}
+
+fn verify(days: &[Day], source: &Source, answers: Option<&Path>) -> Result<(), String> {
+    let answers = load_answers(answers)?;
+    let name = source.name();
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for day in days {
+        let input = source.load(day, |input| day.parse(input))?;
+
+        for part in Part::BOTH {
+            let answer = day.solve(part, &input);
+            let verdict = answers.check(day.number, part, &name, &answer);
+
+            println!("Day {} Part {}: {}", day.number, part, verdict);
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail { expected } => {
+                    println!("    expected: {}", expected);
+                    println!("    got:      {}", answer);
+                    failed += 1;
+                }
+                Verdict::Missing => missing += 1,
+            }
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} missing for input `{}`",
+        passed, failed, missing, name
+    );
+
+    if failed > 0 {
+        return Err(format!(
+            "{} of {} answers didn't match",
+            failed,
+            passed + failed + missing
+        ));
+    }
+
+    Ok(())
+}
+
+fn bench(
+    days: &[Day],
+    iterations: usize,
+    source: &Source,
+    output: Option<&Path>,
+) -> Result<(), String> {
+    let mut benches = Vec::new();
+
+    println!(
+        "{:>6} {:>6} {:>12} {:>12} {:>12}",
+        "day", "stage", "min", "median", "max"
+    );
+    for day in days {
+        let bench = source.load(day, |input| DayBench::run(day, input, iterations))?;
+
+        for (stage, timings) in &bench.stages {
+            println!(
+                "{:>6} {:>6} {:>12} {:>12} {:>12}",
+                day.number,
+                stage.to_string(),
+                format!("{:.1?}", timings.min()),
+                format!("{:.1?}", timings.median()),
+                format!("{:.1?}", timings.max()),
+            );
+        }
+        benches.push(bench);
+    }
+
+    if let Some(path) = output {
+        fs::write(path, bench::write_results(&benches))
+            .map_err(|err| format!("couldn't write results to {}: {}", path.display(), err))?;
+    }
+
+    Ok(())
+}
+
+/// A seed that's different every run, for when none is given.
+fn random_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |since| since.as_nanos() as u64)
+}
+
+fn generate(generator: &Generator, seed: Option<u64>, size: Option<usize>) {
+    let seed = seed.unwrap_or_else(random_seed);
+    let size = size.unwrap_or(generator.default_size);
+
+    // on stderr, so the input can be piped straight into a file
+    eprintln!(
+        "day {} with seed {} and size {} ({})",
+        generator.day, seed, size, generator.size_of
+    );
+    print!("{}", generator.generate(&mut Rng::new(seed), size));
+}
+
+fn fuzz(days: &[Day], iterations: usize, seed: Option<u64>) -> Result<(), String> {
+    let seed = seed.unwrap_or_else(random_seed);
+    let mut rng = Rng::new(seed);
+    let mut crashed = 0;
+
+    // the panics are reported below, along with the input that caused them
+    panic::set_hook(Box::new(|_| {}));
+
+    println!("fuzzing with seed {}", seed);
+    for day in days {
+        match fuzz::fuzz(day, &mut rng, iterations) {
+            Some(crash) => {
+                println!(
+                    "Day {}: panicked with `{}` on {:?}",
+                    day.number, crash.message, crash.input
+                );
+                crashed += 1;
+            }
+            None => println!(
+                "Day {}: parsed {} inputs without panicking",
+                day.number, iterations
+            ),
+        }
+    }
+
+    let _ = panic::take_hook();
+
+    if crashed > 0 {
+        return Err(format!("{} of {} parsers panicked", crashed, days.len()));
+    }
+
+    Ok(())
+}
+
+/// How many columns and rows of cells fit on the terminal, going by the
+/// `COLUMNS` and `LINES` most shells set, less a row for the caption and one
+/// for the prompt when stepping.
+fn terminal_size() -> (usize, usize) {
+    let from_env = |name, default| {
+        env::var(name)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(default)
+    };
+
+    (
+        from_env("COLUMNS", 80),
+        from_env("LINES", 24).saturating_sub(2),
+    )
+}
+
+fn animate(
+    day: &Day,
+    animation: &Animation,
+    source: &Source,
+    player: &Player,
+) -> Result<(), String> {
+    let frames = source.load(day, |input| animation.frames(input))?;
+
+    player
+        .play(frames, &mut io::stdout().lock(), &mut io::stdin().lock())
+        .map(drop)
+        .map_err(|err| format!("couldn't draw the animation: {}", err))
+}
+
+fn export_pictures(
+    day: &Day,
+    export: &Export,
+    source: &Source,
+    format: export::Format,
+    output: &Path,
+) -> Result<(), String> {
+    let pictures = source.load(day, |input| export.pictures(input))?;
+
+    fs::create_dir_all(output)
+        .map_err(|err| format!("couldn't create {}: {}", output.display(), err))?;
+    for picture in &pictures {
+        let path = output.join(format!(
+            "day{}-{}.{}",
+            day.number,
+            picture.name,
+            format.extension()
+        ));
+        fs::write(&path, format.write(picture))
+            .map_err(|err| format!("couldn't write {}: {}", path.display(), err))?;
+        println!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn explore(day: Day, source: Source) -> Result<(), String> {
+    let mut session = Session::start(day, source)?;
+
+    println!("type `help` for the commands");
+    repl::repl(
+        &mut session,
+        &mut io::stdin().lock(),
+        &mut io::stdout().lock(),
+    )
+    .map_err(|err| format!("couldn't run the repl: {}", err))
+}
+
+fn check(day: &Day, source: &Source) -> Result<(), String> {
+    let input = source.load(day, |input| Ok(String::from(input)))?;
+    let problems = check::problems(day, &input);
+
+    for problem in &problems {
+        println!("{}\n", problem.annotate(&input));
+    }
+
+    match problems.len() {
+        0 => {
+            println!("no problems with day {} in {}", day.number, source);
+            Ok(())
+        }
+        1 => Err(format!(
+            "found 1 problem with day {} in {}",
+            day.number, source
+        )),
+        count => Err(format!(
+            "found {} problems with day {} in {}",
+            count, day.number, source
+        )),
+    }
+}
+
+/// Counts how often day 1's depths deepen, summed over windows of `window`
+/// depths rather than the puzzle's one or three.
+fn sweep(window: usize, source: &Source) -> Result<(), String> {
+    let day = days::get(Day1::DAY).expect("day 1 is registered");
+    let depths = source.load(day, Day1::parse)?;
+
+    println!(
+        "{} increases with a window of {}",
+        day1::increases(&depths, window),
+        window
+    );
+    Ok(())
+}
+
+fn main() {
+    let mut args: Vec<_> = env::args().skip(1).collect();
+
+    let command = match args::take_trace(&mut args).and_then(|filter| {
+        if let Some(filter) = filter {
+            trace::init(filter);
+        }
+        args::parse(&args)
+    }) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, args::USAGE);
+            process::exit(2);
+        }
+    };
+
+    let result = match command {
+        Command::Run {
+            days,
+            parts,
+            source,
+            format,
+            jobs,
+        } => run(&days, &parts, &source, format, jobs),
+        Command::Verify {
+            days,
+            source,
+            answers,
+        } => verify(&days, &source, answers.as_deref()),
+        Command::Bench {
+            days,
+            iterations,
+            source,
+            output,
+        } => bench(&days, iterations, &source, output.as_deref()),
+        Command::New { day } => scaffold::create(Path::new("."), day).map(|paths| {
+            for path in paths {
+                println!("wrote {}", path.display());
+            }
+        }),
+        Command::Generate {
+            generator,
+            seed,
+            size,
+        } => {
+            generate(&generator, seed, size);
+            Ok(())
+        }
+        Command::Fuzz {
+            days,
+            iterations,
+            seed,
+        } => fuzz(&days, iterations, seed),
+        Command::Animate {
+            day,
+            animation,
+            source,
+            frame_rate,
+            stepping,
+            max_frames,
+        } => animate(
+            &day,
+            &animation,
+            &source,
+            &Player {
+                frame_rate,
+                stepping,
+                max_frames,
+                size: terminal_size(),
+            },
+        ),
+        Command::Export {
+            day,
+            export,
+            source,
+            format,
+            output,
+        } => export_pictures(&day, &export, &source, format, &output),
+        Command::Repl { day, source } => explore(day, source),
+        Command::Check { day, source } => check(&day, &source),
+        Command::Sweep { window, source } => sweep(window, &source),
+    };
+
+    if let Err(message) = result {
+        eprintln!("error: {}", message);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,260 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The lines of `days/mod.rs` that mention each day, as the text before and
+/// after the day's number.
+const REGISTRATIONS: [(&str, &str); 3] = [
+    ("pub mod day", ";"),
+    ("pub use day", "::Day{};"),
+    ("    Day::of::<Day", ">(),"),
+];
+
+/// The skeleton every day starts from. Parts answer [`Unsolved`] until
+/// they're filled in, so a new day can be run straight away, and its fixture
+/// tests pass until then against the answers [`seed_answers`] files.
+///
+/// [`Unsolved`]: aoc2021::Unsolved
+fn module_source(day: u8) -> String {
+    format!(
+        r#"use std::fmt;
+
+use crate::{{ParseError, Solution, Unsolved}};
+
+fn load_input(input: &str) -> Result<Vec<String>, ParseError> {{
+    Ok(input.lines().map(String::from).collect())
+}}
+
+fn part_one(_input: &[String]) -> Unsolved {{
+    Unsolved
+}}
+
+fn part_two(_input: &[String]) -> Unsolved {{
+    Unsolved
+}}
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const DAY: u8 = {day};
+    const INPUT: &'static str = include_str!("day{day}.txt");
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        load_input(input)
+    }}
+
+    fn part_one(input: &Self::Input) -> impl fmt::Display {{
+        part_one(input)
+    }}
+
+    fn part_two(input: &Self::Input) -> impl fmt::Display {{
+        part_two(input)
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use crate::fixtures::fixture_tests;
+
+    fixture_tests!(Day{day});
+}}
+"#,
+        day = day
+    )
+}
+
+/// The day a registration line is for, if it's one of the `before`/`after`
+/// kind.
+fn registered_day(line: &str, before: &str, after: &str) -> Option<u8> {
+    let rest = line.strip_prefix(before)?;
+    let end = rest.find(|c: char| !c.is_ascii_digit())?;
+    let day: u8 = rest[..end].parse().ok()?;
+
+    if rest[end..] == after.replace("{}", &day.to_string()) {
+        Some(day)
+    } else {
+        None
+    }
+}
+
+/// Adds `day` to each list of days in `mod_rs`, keeping them in order.
+fn register(mod_rs: &str, day: u8) -> Result<String, String> {
+    let mut lines: Vec<String> = mod_rs.lines().map(String::from).collect();
+
+    for (before, after) in REGISTRATIONS {
+        let days: Vec<_> = lines
+            .iter()
+            .enumerate()
+            .filter_map(|(i, line)| Some((i, registered_day(line, before, after)?)))
+            .collect();
+
+        if days.iter().any(|(_, registered)| *registered == day) {
+            return Err(format!("day {} is already registered", day));
+        }
+        let index = match days.iter().rev().find(|(_, registered)| *registered < day) {
+            Some((i, _)) => i + 1,
+            None => days
+                .first()
+                .map(|(i, _)| *i)
+                .ok_or(format!("couldn't find where `{}` lines go", before.trim()))?,
+        };
+
+        let line = format!("{}{}{}", before, day, after.replace("{}", &day.to_string()));
+        lines.insert(index, line);
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Files an `unsolved` answer to both parts of `day` under `name` in
+/// `answers`, keeping the days in order, for the day's fixture tests to
+/// check until the real answers are filled in.
+fn seed_answers(answers: &str, day: u8, name: &str) -> Result<String, String> {
+    let day_of = |line: &str| {
+        let mut split = line.split(' ');
+        let day = split.next()?.parse::<u8>().ok()?;
+        Some((day, split.nth(1)? == name))
+    };
+    let mut lines: Vec<String> = answers.lines().map(String::from).collect();
+
+    if lines.iter().any(|line| day_of(line) == Some((day, true))) {
+        return Err(format!("day {} already has `{}` answers", day, name));
+    }
+    let index = lines
+        .iter()
+        .position(|line| day_of(line).is_some_and(|(filed, _)| filed > day))
+        .unwrap_or(lines.len());
+    for part in [2, 1] {
+        lines.insert(index, format!("{} {} {} unsolved", day, part, name));
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds day `day` to the crate at `root`, returning the files it created or
+/// changed. Nothing is touched if the day already exists.
+pub fn create(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let days = root.join("src/days");
+    let mod_rs = days.join("mod.rs");
+    let module = days.join(format!("day{}.rs", day));
+    let input = days.join(format!("day{}.txt", day));
+    let known = root.join("answers.txt");
+    let sample = root.join(format!("fixtures/sample/day{}.txt", day));
+    let sample_answers = root.join("fixtures/answers.txt");
+
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|err| {
+            format!(
+                "couldn't read {}, run this from the root of the crate: {}",
+                path.display(),
+                err
+            )
+        })
+    };
+    let registry = read(&mod_rs)?;
+    let known_answers = read(&known)?;
+    let sample_answers_text = read(&sample_answers)?;
+
+    for path in [&module, &input, &sample] {
+        if path.exists() {
+            return Err(format!("{} already exists", path.display()));
+        }
+    }
+    let registry = register(&registry, day)?;
+    let known_answers = seed_answers(&known_answers, day, "embedded")?;
+    let sample_answers_text = seed_answers(&sample_answers_text, day, "sample")?;
+
+    let write = |path: &Path, contents: &str| {
+        fs::write(path, contents)
+            .map_err(|err| format!("couldn't write {}: {}", path.display(), err))
+    };
+    write(&module, &module_source(day))?;
+    write(&input, "")?;
+    write(&mod_rs, &registry)?;
+    write(&sample, "")?;
+    write(&known, &known_answers)?;
+    write(&sample_answers, &sample_answers_text)?;
+
+    Ok(vec![module, input, mod_rs, sample, known, sample_answers])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD_RS: &str = include_str!("../../days/mod.rs");
+
+    #[test]
+    fn test_register() {
+        let registry = register(MOD_RS, 30).unwrap();
+
+        assert!(registry.contains("pub mod day30;\n"));
+        assert!(registry.contains("pub use day30::Day30;\n"));
+        assert!(registry.contains("    Day::of::<Day30>(),\n];"));
+
+        let registry = register(
+            "pub mod day3;\npub use day3::Day3;\n    Day::of::<Day3>(),\n",
+            1,
+        );
+        assert_eq!(
+            registry.unwrap(),
+            "pub mod day1;
+pub mod day3;
+pub use day1::Day1;
+pub use day3::Day3;
+    Day::of::<Day1>(),
+    Day::of::<Day3>(),
+"
+        );
+
+        assert!(register(MOD_RS, 1).is_err());
+    }
+
+    #[test]
+    fn test_seed_answers() {
+        let answers = "# header\n1 1 sample 7\n1 2 sample 5\n3 1 sample 9\n";
+
+        assert_eq!(
+            seed_answers(answers, 2, "sample").unwrap(),
+            "# header\n1 1 sample 7\n1 2 sample 5\n2 1 sample unsolved\n2 2 sample unsolved\n\
+             3 1 sample 9\n"
+        );
+        assert!(seed_answers(answers, 4, "sample")
+            .unwrap()
+            .ends_with("3 1 sample 9\n4 1 sample unsolved\n4 2 sample unsolved\n"));
+        assert!(seed_answers(answers, 1, "sample").is_err());
+        assert!(seed_answers(answers, 1, "embedded").is_ok());
+    }
+
+    #[test]
+    fn test_create() {
+        let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        let days = root.join("src/days");
+        fs::create_dir_all(&days).unwrap();
+        fs::create_dir_all(root.join("fixtures/sample")).unwrap();
+        fs::write(days.join("mod.rs"), MOD_RS).unwrap();
+        fs::write(root.join("answers.txt"), "1 1 embedded 1451\n").unwrap();
+        fs::write(root.join("fixtures/answers.txt"), "").unwrap();
+
+        let created = create(&root, 30).unwrap();
+
+        assert_eq!(created.len(), 6);
+        assert_eq!(fs::read_to_string(days.join("day30.txt")).unwrap(), "");
+        assert_eq!(
+            fs::read_to_string(root.join("fixtures/sample/day30.txt")).unwrap(),
+            ""
+        );
+        assert!(fs::read_to_string(days.join("day30.rs"))
+            .unwrap()
+            .contains("    fixture_tests!(Day30);\n"));
+        assert_eq!(
+            fs::read_to_string(root.join("fixtures/answers.txt")).unwrap(),
+            "30 1 sample unsolved\n30 2 sample unsolved\n"
+        );
+        assert!(create(&root, 30).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,166 @@
+use crate::days::{day10, day11, day20, day3, day4, day8, day9};
+use crate::{Day, ParseError};
+
+/// A day's own checks on the shape of its input, which carry on past the
+/// first problem where the parser stops.
+#[derive(Debug, Clone, Copy)]
+pub struct Validation {
+    pub day: u8,
+    validate: fn(&str) -> Vec<ParseError>,
+}
+
+impl Validation {
+    pub fn validate(&self, input: &str) -> Vec<ParseError> {
+        (self.validate)(input)
+    }
+}
+
+pub const ALL: &[Validation] = &[
+    Validation {
+        day: 3,
+        validate: day3::validate,
+    },
+    Validation {
+        day: 4,
+        validate: day4::validate,
+    },
+    Validation {
+        day: 8,
+        validate: day8::validate,
+    },
+    Validation {
+        day: 9,
+        validate: day9::validate,
+    },
+    Validation {
+        day: 10,
+        validate: day10::validate,
+    },
+    Validation {
+        day: 11,
+        validate: day11::validate,
+    },
+    Validation {
+        day: 20,
+        validate: day20::validate,
+    },
+];
+
+pub fn get(day: u8) -> Option<&'static Validation> {
+    ALL.iter().find(|validation| validation.day == day)
+}
+
+/// Problems any input can have, whatever the day: stray whitespace at the
+/// ends of lines, which tends to come along with pasting.
+fn whitespace(input: &str) -> Vec<ParseError> {
+    input
+        .split('\n')
+        .filter_map(|line| {
+            let trimmed = line.trim_end();
+            let trailing = &line[trimmed.len()..];
+
+            match trailing {
+                "" => None,
+                "\r" => Some(ParseError::at(
+                    input,
+                    trailing,
+                    "a `\\n` line ending, not `\\r\\n`",
+                )),
+                _ => Some(ParseError::at(input, trailing, "no trailing whitespace")),
+            }
+        })
+        .collect()
+}
+
+/// Everything wrong with `input` as `day`'s input, in the order it comes in
+/// the input: the whitespace, the day's own [`Validation`] if it has one, and
+/// the parser's error unless one of those already points at the same spot.
+pub fn problems(day: &Day, input: &str) -> Vec<ParseError> {
+    if input.trim().is_empty() {
+        let err = day.parse(input).err();
+        return vec![err.unwrap_or_else(|| ParseError::at(input, input, "an input"))];
+    }
+
+    let mut problems = whitespace(input);
+    if let Some(validation) = get(day.number) {
+        problems.extend(validation.validate(input));
+    }
+    if let Err(err) = day.parse(input) {
+        if !problems
+            .iter()
+            .any(|problem| (problem.line, problem.column) == (err.line, err.column))
+        {
+            problems.push(err);
+        }
+    }
+
+    problems.sort_by_key(|problem| (problem.line, problem.column));
+    problems.dedup();
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_whitespace() {
+        let problems = whitespace("1 \n2\r\n3\n");
+
+        assert_eq!(problems.len(), 2);
+        assert_eq!((problems[0].line, problems[0].column), (1, 2));
+        assert_eq!(problems[1].expected, "a `\\n` line ending, not `\\r\\n`");
+    }
+
+    #[test]
+    fn test_embedded_inputs_have_no_problems() {
+        for day in days::ALL {
+            assert_eq!(problems(day, day.input), vec![], "day {}", day.number);
+        }
+    }
+
+    #[test]
+    fn test_every_problem_is_found() {
+        let day = days::get(11).unwrap();
+        let input = "123\n4x6 \n789\n";
+
+        let found: Vec<_> = problems(day, input)
+            .iter()
+            .map(|problem| (problem.line, problem.column))
+            .collect();
+
+        assert_eq!(found, [(1, 1), (2, 1), (2, 2), (2, 4), (3, 1), (4, 1)]);
+    }
+
+    #[test]
+    fn test_inputs_without_puzzles() {
+        let found = |day, input| {
+            problems(days::get(day).unwrap(), input)
+                .into_iter()
+                .map(|problem| (problem.line, problem.column, problem.expected))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            found(3, "\n"),
+            [(1, 1, String::from("at least one number in the report"))]
+        );
+        assert_eq!(
+            found(4, "7,4,9\n"),
+            [(1, 6, String::from("at least one board"))]
+        );
+        assert_eq!(
+            found(10, "()\n{[]}\n"),
+            [(2, 5, String::from("at least one incomplete line"))]
+        );
+        assert_eq!(found(10, "(\n(x\n"), [(2, 2, String::from("a bracket"))]);
+    }
+
+    #[test]
+    fn test_parser_errors_are_kept() {
+        let day = days::get(1).unwrap();
+
+        assert_eq!(problems(day, "12\nfish\n")[0].expected, "a depth");
+    }
+}
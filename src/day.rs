@@ -0,0 +1,174 @@
+use std::any::{self, Any};
+use std::fmt;
+
+use crate::{ParseError, Solution, Unsolved};
+
+/// One of the two halves of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(number: u8) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A part's answer, written out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub text: String,
+    /// Whether the part has been solved, rather than answering [`Unsolved`].
+    pub solved: bool,
+}
+
+impl Answer {
+    fn of(answer: impl fmt::Display) -> Answer {
+        Answer {
+            solved: any::type_name_of_val(&answer) != any::type_name::<Unsolved>(),
+            text: answer.to_string(),
+        }
+    }
+}
+
+/// A parsed puzzle input whose type is only known to the day that parsed it.
+pub struct Parsed(Box<dyn Any>);
+
+/// A [`Solution`] with its types erased, so a day can be picked at runtime.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub input: &'static str,
+    parse: fn(&str) -> Result<Parsed, ParseError>,
+    part_one: fn(&Parsed) -> Answer,
+    part_two: fn(&Parsed) -> Answer,
+}
+
+impl Day {
+    pub const fn of<S>() -> Day
+    where
+        S: Solution,
+        S::Input: 'static,
+    {
+        Day {
+            number: S::DAY,
+            input: S::INPUT,
+            parse: parse::<S>,
+            part_one: part_one::<S>,
+            part_two: part_two::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Parsed, ParseError> {
+        (self.parse)(input)
+    }
+
+    /// Answers one part of the puzzle. `input` must have come from this
+    /// day's [`Day::parse`].
+    pub fn solve(&self, part: Part, input: &Parsed) -> String {
+        self.answer(part, input).text
+    }
+
+    /// Like [`Day::solve`], but also says whether the part is solved yet.
+    pub fn answer(&self, part: Part, input: &Parsed) -> Answer {
+        match part {
+            Part::One => (self.part_one)(input),
+            Part::Two => (self.part_two)(input),
+        }
+    }
+}
+
+impl fmt::Debug for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Day").field("number", &self.number).finish()
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<Parsed, ParseError>
+where
+    S::Input: 'static,
+{
+    Ok(Parsed(Box::new(S::parse(input)?)))
+}
+
+fn downcast<S: Solution>(input: &Parsed) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .0
+        .downcast_ref()
+        .unwrap_or_else(|| panic!("input was not parsed by day {}", S::DAY))
+}
+
+fn part_one<S: Solution>(input: &Parsed) -> Answer
+where
+    S::Input: 'static,
+{
+    Answer::of(S::part_one(downcast::<S>(input)))
+}
+
+fn part_two<S: Solution>(input: &Parsed) -> Answer
+where
+    S::Input: 'static,
+{
+    Answer::of(S::part_two(downcast::<S>(input)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Half;
+
+    impl Solution for Half {
+        const DAY: u8 = 0;
+        const INPUT: &'static str = "";
+
+        type Input = ();
+
+        fn parse(_input: &str) -> Result<Self::Input, ParseError> {
+            Ok(())
+        }
+
+        fn part_one(_input: &Self::Input) -> impl fmt::Display {
+            "unsolved"
+        }
+    }
+
+    #[test]
+    fn test_answers_say_whether_they_are_solved() {
+        let day = Day::of::<Half>();
+        let parsed = day.parse("").unwrap();
+
+        assert_eq!(
+            day.answer(Part::One, &parsed),
+            Answer {
+                text: String::from("unsolved"),
+                solved: true,
+            }
+        );
+        assert!(!day.answer(Part::Two, &parsed).solved);
+    }
+}
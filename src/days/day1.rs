@@ -0,0 +1,116 @@
+use std::fmt;
+use std::iter::Sum;
+
+use crate::{parse, ParseError, Solution};
+
+/// The sums of every run of `size` items in a row, sliding along the items
+/// one at a time.
+pub struct WindowSums<'a, T> {
+    items: &'a [T],
+    size: usize,
+    index: usize,
+}
+
+impl<'a, T> WindowSums<'a, T> {
+    /// Panics if `size` is 0.
+    pub fn new(items: &'a [T], size: usize) -> WindowSums<'a, T> {
+        assert!(size > 0, "windows must be at least one item long");
+
+        WindowSums {
+            items,
+            size,
+            index: 0,
+        }
+    }
+}
+
+impl<'a, T: Copy + Sum> Iterator for WindowSums<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = self.items.get(self.index..self.index + self.size)?;
+        self.index += 1;
+
+        Some(window.iter().copied().sum())
+    }
+}
+
+/// How many times the sum of a window of `size` items is more than the one
+/// before it.
+pub fn increases<T: Copy + Sum + PartialOrd>(items: &[T], size: usize) -> usize {
+    let sums: Vec<_> = WindowSums::new(items, size).collect();
+
+    sums.windows(2).filter(|pair| pair[1] > pair[0]).count()
+}
+
+fn part_one(depths: &[i32]) -> usize {
+    increases(depths, 1)
+}
+
+fn part_two(depths: &[i32]) -> usize {
+    increases(depths, 3)
+}
+
+fn load_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| parse::field(input, line, "a depth"))
+        .collect()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const INPUT: &'static str = include_str!("day1.txt");
+
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(depths: &Self::Input) -> impl fmt::Display {
+        part_one(depths)
+    }
+
+    fn part_two(depths: &Self::Input) -> impl fmt::Display {
+        part_two(depths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::fixture_tests;
+
+    fixture_tests!(Day1);
+
+    #[test]
+    fn test_window_sums() {
+        let sums: Vec<_> = WindowSums::new(&[1, 2, 3, 4], 2).collect();
+
+        assert_eq!(sums, [3, 5, 7]);
+        assert_eq!(WindowSums::new(&[1.5, 2.0], 2).collect::<Vec<_>>(), [3.5]);
+        assert_eq!(WindowSums::new(&[1u64, 2], 3).next(), None);
+    }
+
+    #[test]
+    fn test_short_inputs() {
+        for size in 1..=4 {
+            assert_eq!(increases::<i32>(&[], size), 0);
+            assert_eq!(increases(&[7], size), 0);
+            assert_eq!(increases(&[7, 8], size), usize::from(size == 1));
+        }
+    }
+
+    #[test]
+    fn test_wider_windows() {
+        let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+        assert_eq!(increases(&depths, 3), 5);
+        assert_eq!(increases(&depths, 10), 0);
+        assert_eq!(increases(&depths, 9), 1);
+    }
+}
@@ -0,0 +1,202 @@
+use std::fmt;
+
+use crate::{ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoundType {
+    Paren,
+    Bracket,
+    CurlyBrace,
+    AngleBracket,
+}
+
+#[derive(Debug)]
+pub enum ChunkBound {
+    Open(BoundType),
+    Close(BoundType),
+}
+
+fn load_input(input: &str) -> Result<Vec<Vec<ChunkBound>>, ParseError> {
+    let lines = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.char_indices()
+                .map(|(i, c)| {
+                    bound(c).ok_or_else(|| ParseError::at(input, &line[i..], "a bracket"))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if !any_incomplete(&lines) {
+        return Err(no_incomplete_lines(input));
+    }
+
+    Ok(lines)
+}
+
+fn bound(c: char) -> Option<ChunkBound> {
+    match c {
+        '(' => Some(ChunkBound::Open(BoundType::Paren)),
+        '[' => Some(ChunkBound::Open(BoundType::Bracket)),
+        '{' => Some(ChunkBound::Open(BoundType::CurlyBrace)),
+        '<' => Some(ChunkBound::Open(BoundType::AngleBracket)),
+        ')' => Some(ChunkBound::Close(BoundType::Paren)),
+        ']' => Some(ChunkBound::Close(BoundType::Bracket)),
+        '}' => Some(ChunkBound::Close(BoundType::CurlyBrace)),
+        '>' => Some(ChunkBound::Close(BoundType::AngleBracket)),
+        _ => None,
+    }
+}
+
+/// Part two takes the middle score of the incomplete lines, so there has to
+/// be one.
+fn any_incomplete(lines: &[Vec<ChunkBound>]) -> bool {
+    lines
+        .iter()
+        .any(|line| matches!(validate_line(line), ValidateResult::Incomplete(_)))
+}
+
+fn no_incomplete_lines(input: &str) -> ParseError {
+    ParseError::after(input, input.trim_end(), "at least one incomplete line")
+}
+
+/// Every character that isn't a bracket, rather than just the first, or
+/// once they're all brackets, the lack of an incomplete line.
+pub(crate) fn validate(input: &str) -> Vec<ParseError> {
+    let mut problems = vec![];
+    let mut lines = vec![];
+
+    for line in input.lines().filter(|line| !line.is_empty()) {
+        let bounds: Option<Vec<_>> = line.chars().map(bound).collect();
+        match bounds {
+            Some(bounds) => lines.push(bounds),
+            None => problems.extend(
+                line.char_indices()
+                    .filter(|(_, c)| bound(*c).is_none())
+                    .map(|(i, _)| ParseError::at(input, &line[i..], "a bracket")),
+            ),
+        }
+    }
+    if problems.is_empty() && !any_incomplete(&lines) {
+        problems.push(no_incomplete_lines(input));
+    }
+
+    problems
+}
+
+enum ValidateResult {
+    Valid,
+    Incomplete(Vec<BoundType>),
+    Corrupt(BoundType),
+}
+
+fn validate_line(line: &[ChunkBound]) -> ValidateResult {
+    let mut stack: Vec<BoundType> = Vec::new();
+
+    for bound in line {
+        match bound {
+            ChunkBound::Open(bound_type) => {
+                stack.push(*bound_type);
+            }
+            ChunkBound::Close(bound_type) => match stack.pop() {
+                None => return ValidateResult::Corrupt(*bound_type),
+                Some(found_type) if found_type != *bound_type => {
+                    return ValidateResult::Corrupt(*bound_type);
+                }
+                _ => (),
+            },
+        }
+    }
+
+    if !stack.is_empty() {
+        ValidateResult::Incomplete(stack)
+    } else {
+        ValidateResult::Valid
+    }
+}
+
+fn part_one(lines: &[Vec<ChunkBound>]) -> u32 {
+    let mut corrupt_score = 0;
+
+    for line in lines {
+        corrupt_score += match validate_line(line) {
+            ValidateResult::Corrupt(bound_type) => match bound_type {
+                BoundType::Paren => 3,
+                BoundType::Bracket => 57,
+                BoundType::CurlyBrace => 1197,
+                BoundType::AngleBracket => 25137,
+            },
+            _ => 0,
+        }
+    }
+
+    corrupt_score
+}
+
+fn part_two(lines: &[Vec<ChunkBound>]) -> u64 {
+    let mut incomplete_scores: Vec<u64> = Vec::new();
+
+    for line in lines {
+        if let ValidateResult::Incomplete(rem_stack) = validate_line(line) {
+            let mut score = 0;
+
+            for bound_type in rem_stack.iter().rev() {
+                score *= 5;
+                score += match bound_type {
+                    BoundType::Paren => 1,
+                    BoundType::Bracket => 2,
+                    BoundType::CurlyBrace => 3,
+                    BoundType::AngleBracket => 4,
+                };
+            }
+
+            incomplete_scores.push(score);
+        }
+    }
+
+    incomplete_scores.sort();
+
+    incomplete_scores[incomplete_scores.len() / 2]
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const INPUT: &'static str = include_str!("day10.txt");
+
+    type Input = Vec<Vec<ChunkBound>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(lines: &Self::Input) -> impl fmt::Display {
+        part_one(lines)
+    }
+
+    fn part_two(lines: &Self::Input) -> impl fmt::Display {
+        part_two(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::fixture_tests;
+
+    fixture_tests!(Day10);
+
+    #[test]
+    fn test_no_incomplete_lines() {
+        for input in ["", "(]\n<>\n", "{()}"] {
+            let err = load_input(input).unwrap_err();
+
+            assert_eq!(err.expected, "at least one incomplete line");
+        }
+
+        assert!(load_input("(]\n<\n").is_ok());
+    }
+}
@@ -0,0 +1,224 @@
+use std::fmt;
+use std::iter;
+
+use colored::Color;
+
+use crate::animate::{Cell, Frame, Frames};
+use crate::grid::Grid;
+use crate::repl::{self, Explorer};
+use crate::search;
+use crate::{ParseError, Solution};
+
+fn load_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    let octopi = Grid::digits(input, input)?;
+
+    if octopi.width() != 10 || octopi.height() != 10 {
+        return Err(ParseError::at(
+            input,
+            input,
+            "a 10x10 grid of energy levels",
+        ));
+    }
+
+    Ok(octopi)
+}
+
+/// Every energy level that isn't a digit, and every row that isn't 10 long,
+/// rather than just the first.
+pub(crate) fn validate(input: &str) -> Vec<ParseError> {
+    let mut problems = vec![];
+    let mut rows: Vec<_> = input.lines().map(str::trim_end).collect();
+    while rows.last() == Some(&"") {
+        rows.pop();
+    }
+
+    for row in &rows {
+        for (i, c) in row.char_indices() {
+            if !c.is_ascii_digit() {
+                problems.push(ParseError::at(input, &row[i..], "a digit"));
+            }
+        }
+        if row.chars().count() != 10 {
+            problems.push(ParseError::at(input, row, "a row of 10 digits"));
+        }
+    }
+    if rows.len() != 10 {
+        problems.push(ParseError::after(input, input, "10 rows"));
+    }
+
+    problems
+}
+
+pub(crate) fn perform_step(octopi: &mut Grid<u32>) -> u32 {
+    let mut flashes = 0;
+
+    let mut update_positions: Vec<_> = octopi.positions().collect();
+    let mut first_pass = true;
+
+    while !update_positions.is_empty() {
+        let mut next_update_positions = Vec::new();
+        for pos in update_positions {
+            octopi[pos] = if octopi[pos] == 9 {
+                flashes += 1;
+                next_update_positions.extend(octopi.neighbours8(pos));
+                0
+            } else if octopi[pos] != 0 || first_pass {
+                octopi[pos] + 1
+            } else {
+                octopi[pos]
+            };
+        }
+        first_pass = false;
+        update_positions = next_update_positions;
+    }
+
+    flashes
+}
+
+fn part_one(mut octopi: Grid<u32>, steps: u32) -> u32 {
+    let mut flashes = 0;
+
+    for _ in 0..steps {
+        flashes += perform_step(&mut octopi);
+    }
+
+    flashes
+}
+
+/// The first step on which every octopus flashes, or `None` if they never
+/// do.
+fn part_two(octopi: Grid<u32>) -> Option<usize> {
+    let all = (octopi.width() * octopi.height()) as u32;
+
+    search::steps_until(octopi, |octopi| perform_step(octopi) == all)
+}
+
+/// The octopuses after every step, with the ones that just flashed lit up,
+/// until they all flash at once.
+pub(crate) fn frames(input: &str) -> Result<Frames, ParseError> {
+    let octopi = load_input(input)?;
+    let all = (octopi.width() * octopi.height()) as u32;
+
+    let frames = iter::successors(Some((octopi, 0, 0)), move |(octopi, step, flashes)| {
+        if *flashes == all {
+            return None;
+        }
+        let mut octopi = octopi.clone();
+        let flashes = perform_step(&mut octopi);
+        Some((octopi, step + 1, flashes))
+    })
+    .map(|(octopi, step, flashes)| Frame {
+        caption: format!("step {}: {} flashes", step, flashes),
+        cells: octopi.map(|energy| match energy {
+            0 => Cell::colored('0', Color::BrightYellow),
+            _ => Cell::colored(char::from_digit(*energy, 10).unwrap(), Color::Blue),
+        }),
+    });
+
+    Ok(Box::new(frames))
+}
+
+/// The octopuses, stepped through on request.
+struct Cavern {
+    octopi: Grid<u32>,
+    step: usize,
+    flashes: u32,
+}
+
+impl Explorer for Cavern {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("step", "take a step, or <n> steps"),
+            ("show", "print the energy levels"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "step" => {
+                for _ in 0..repl::count(args)? {
+                    self.flashes += perform_step(&mut self.octopi);
+                    self.step += 1;
+                }
+                Ok(format!(
+                    "step {}: {} flashes so far",
+                    self.step, self.flashes
+                ))
+            }
+            _ => Ok(format!("step {}:\n{}", self.step, self.octopi)),
+        }
+    }
+}
+
+pub(crate) fn explore(input: &str) -> Result<Box<dyn Explorer>, ParseError> {
+    Ok(Box::new(Cavern {
+        octopi: load_input(input)?,
+        step: 0,
+        flashes: 0,
+    }))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const INPUT: &'static str = include_str!("day11.txt");
+
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(octopi: &Self::Input) -> impl fmt::Display {
+        part_one(octopi.clone(), 100)
+    }
+
+    fn part_two(octopi: &Self::Input) -> impl fmt::Display {
+        part_two(octopi.clone())
+            .map_or_else(|| String::from("never syncs"), |step| step.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, fixture_tests};
+
+    fixture_tests!(Day11);
+
+    #[test]
+    fn test_part_one_sample() {
+        let input = load_input(&fixtures::input(11, "sample")).unwrap();
+
+        assert_eq!(part_one(input, 10), 204);
+    }
+
+    #[test]
+    fn test_never_syncs() {
+        let input = "
+2914177763
+1706690743
+9150008063
+6083778353
+3740681241
+5868344978
+6907366258
+5178128657
+0704999622
+8303883685
+";
+        let octopi = load_input(input).unwrap();
+
+        assert_eq!(part_two(octopi.clone()), None);
+        assert_eq!(Day11::part_two(&octopi).to_string(), "never syncs");
+    }
+
+    #[test]
+    fn test_frames_end_when_all_flash() {
+        let frames: Vec<_> = frames(&fixtures::input(11, "sample")).unwrap().collect();
+
+        assert_eq!(frames.len(), 196);
+        assert_eq!(frames[195].caption, "step 195: 100 flashes");
+    }
+}
@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{search, ParseError, Solution};
+
+#[derive(Debug, Clone)]
+enum Revisitable {
+    Always,
+    Once,
+    Never,
+}
+
+#[derive(Debug, Clone)]
+struct Cave {
+    name: String,
+    revisitable: Revisitable,
+}
+
+impl Cave {
+    fn from_name(name: &str) -> Cave {
+        Cave {
+            name: String::from(name),
+            revisitable: match name {
+                "start" | "end" => Revisitable::Never,
+                other => {
+                    if other.chars().all(|c| c.is_uppercase()) {
+                        Revisitable::Always
+                    } else {
+                        Revisitable::Once
+                    }
+                }
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CaveSystem {
+    caves: Vec<Cave>,
+    start_index: usize,
+    end_index: usize,
+    paths_map: HashMap<String, Vec<usize>>,
+}
+
+/// Where a walk through the caves has got to, and what it's been through on
+/// the way. Walks can't go round in circles, as every step into a small cave
+/// marks it visited and two big caves are never joined.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Walk {
+    cave: usize,
+    /// A bit per cave index, set once the walk has been through it.
+    visited: u64,
+    revisited: bool,
+}
+
+impl CaveSystem {
+    fn next_walks(&self, walk: &Walk, may_revisit: bool) -> Vec<Walk> {
+        self.paths_map
+            .get(&self.caves[walk.cave].name)
+            .unwrap()
+            .iter()
+            .filter_map(|&cave| {
+                let bit = 1 << cave;
+                match self.caves[cave].revisitable {
+                    Revisitable::Always => Some(Walk { cave, ..*walk }),
+                    _ if walk.visited & bit == 0 => Some(Walk {
+                        cave,
+                        visited: walk.visited | bit,
+                        revisited: walk.revisited,
+                    }),
+                    Revisitable::Once if may_revisit && !walk.revisited => Some(Walk {
+                        cave,
+                        visited: walk.visited,
+                        revisited: true,
+                    }),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Counts the paths from start to end, which may go through a single
+    /// small cave twice if `may_revisit` is set.
+    fn count_paths(&self, may_revisit: bool) -> u64 {
+        let start = Walk {
+            cave: self.start_index,
+            visited: 1 << self.start_index,
+            revisited: false,
+        };
+
+        search::count_paths(
+            start,
+            |walk| self.next_walks(walk, may_revisit),
+            |walk| walk.cave == self.end_index,
+        )
+    }
+}
+
+fn load_cave_name<'a>(input: &str, name: &'a str) -> Result<&'a str, ParseError> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(ParseError::at(input, name, "a cave name"));
+    }
+
+    Ok(name)
+}
+
+fn load_input(input: &str) -> Result<CaveSystem, ParseError> {
+    let mut uniq_caves: Vec<Cave> = Vec::new();
+    let pairs: Vec<_> = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (name_a, name_b) = line
+                .split_once('-')
+                .ok_or_else(|| ParseError::after(input, line, "`-`"))?;
+
+            let cave_a = Cave::from_name(load_cave_name(input, name_a)?);
+            let cave_b = Cave::from_name(load_cave_name(input, name_b)?);
+
+            if let (Revisitable::Always, Revisitable::Always) =
+                (&cave_a.revisitable, &cave_b.revisitable)
+            {
+                // a path could bounce between these two forever
+                return Err(ParseError::at(input, line, "a small cave on one end"));
+            }
+
+            if uniq_caves.iter().all(|cave| cave.name != cave_a.name) {
+                uniq_caves.push(cave_a.clone());
+            }
+            if uniq_caves.iter().all(|cave| cave.name != cave_b.name) {
+                uniq_caves.push(cave_b.clone());
+            }
+
+            Ok((cave_a, cave_b))
+        })
+        .collect::<Result<_, _>>()?;
+
+    if uniq_caves.len() > 64 {
+        return Err(ParseError::at(input, input, "at most 64 caves"));
+    }
+
+    let mut paths_map = HashMap::new();
+
+    for (cave_a, cave_b) in pairs {
+        let cave_a_pos = uniq_caves
+            .iter()
+            .position(|c| c.name == cave_a.name)
+            .unwrap();
+        let cave_b_pos = uniq_caves
+            .iter()
+            .position(|c| c.name == cave_b.name)
+            .unwrap();
+
+        let cave_a_entry = paths_map.entry(cave_a.name).or_insert(Vec::new());
+        cave_a_entry.push(cave_b_pos);
+
+        let cave_b_entry = paths_map.entry(cave_b.name).or_insert(Vec::new());
+        cave_b_entry.push(cave_a_pos);
+    }
+
+    let start_index = uniq_caves
+        .iter()
+        .position(|cave| cave.name == "start")
+        .ok_or_else(|| ParseError::after(input, input, "a path from `start`"))?;
+    let end_index = uniq_caves
+        .iter()
+        .position(|cave| cave.name == "end")
+        .ok_or_else(|| ParseError::after(input, input, "a path to `end`"))?;
+
+    Ok(CaveSystem {
+        caves: uniq_caves,
+        start_index,
+        end_index,
+        paths_map,
+    })
+}
+
+fn part_one(cave_sys: &CaveSystem) -> u64 {
+    cave_sys.count_paths(false)
+}
+
+fn part_two(cave_sys: &CaveSystem) -> u64 {
+    cave_sys.count_paths(true)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const INPUT: &'static str = include_str!("day12.txt");
+
+    type Input = CaveSystem;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(cave_sys: &Self::Input) -> impl fmt::Display {
+        part_one(cave_sys)
+    }
+
+    fn part_two(cave_sys: &Self::Input) -> impl fmt::Display {
+        part_two(cave_sys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::fixture_tests;
+
+    fixture_tests!(Day12);
+}
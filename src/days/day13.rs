@@ -0,0 +1,351 @@
+use std::fmt;
+use std::iter;
+
+use colored::Color;
+
+use crate::animate::{Cell, Frame, Frames};
+use crate::export::Picture;
+use crate::grid::{Grid, Pos};
+use crate::repl::{self, Explorer};
+use crate::{parse, ParseError, Solution};
+
+#[derive(Debug)]
+pub enum Fold {
+    X(usize),
+    Y(usize),
+}
+
+/// Where a dot at `at` lands when the paper is folded along `axis`, or `None`
+/// if it's on the fold line or too far out to land on the paper.
+fn fold_coord(at: usize, axis: usize) -> Option<usize> {
+    if at < axis {
+        Some(at)
+    } else if at > axis {
+        axis.checked_sub(at - axis)
+    } else {
+        None
+    }
+}
+
+fn perform_fold(dots: &Grid<bool>, fold: &Fold) -> Grid<bool> {
+    let (width, height) = match fold {
+        Fold::X(axis) => (*axis, dots.height()),
+        Fold::Y(axis) => (dots.width(), *axis),
+    };
+    let mut folded = Grid::new(width, height, false);
+
+    for (pos, dot) in dots.iter() {
+        if !dot {
+            continue;
+        }
+
+        let pos = match fold {
+            Fold::X(axis) => fold_coord(pos.x, *axis).map(|x| Pos::new(x, pos.y)),
+            Fold::Y(axis) => fold_coord(pos.y, *axis).map(|y| Pos::new(pos.x, y)),
+        };
+        if let Some(dot) = pos.and_then(|pos| folded.get_mut(pos)) {
+            *dot = true;
+        }
+    }
+
+    folded
+}
+
+fn count_dots(dots: &Grid<bool>) -> usize {
+    dots.cells().filter(|dot| **dot).count()
+}
+
+/// The most cells the paper can have, so a stray far off dot can't ask for
+/// more memory than there is.
+const MAX_CELLS: usize = 1 << 24;
+
+fn load_input(input: &str) -> Result<(Grid<bool>, Vec<Fold>), ParseError> {
+    let mut points: Vec<(usize, usize, &str)> = vec![];
+    let mut folds: Vec<(Fold, &str)> = vec![];
+
+    let mut lines = input.lines();
+    let mut last_line = "";
+
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+
+        let mut split = line.split(',');
+
+        let x = parse::next(input, line, &mut split, "an x coordinate")?;
+        let y = parse::next(input, line, &mut split, "`,`")?;
+
+        points.push((
+            parse::field(input, x, "an x coordinate")?,
+            parse::field(input, y, "a y coordinate")?,
+            line,
+        ));
+        last_line = line;
+    }
+
+    if points.is_empty() {
+        return Err(ParseError::at(input, input, "a dot"));
+    }
+
+    for fold_line in lines {
+        if !fold_line.is_empty() {
+            let fold = parse::prefix(input, fold_line, "fold along ")?;
+            let (axis, value) = fold
+                .split_once('=')
+                .ok_or_else(|| ParseError::after(input, fold, "`=`"))?;
+
+            let value: usize = parse::field(input, value, "a line to fold along")?;
+
+            let fold = match axis {
+                "x" => Fold::X(value),
+                "y" => Fold::Y(value),
+                _ => return Err(ParseError::at(input, axis, "`x` or `y`")),
+            };
+            folds.push((fold, fold_line));
+        }
+        last_line = fold_line;
+    }
+
+    if folds.is_empty() {
+        return Err(ParseError::after(input, last_line, "a fold"));
+    }
+
+    let width = points.iter().map(|(x, ..)| x + 1).max().unwrap();
+    let height = points.iter().map(|(_, y, _)| y + 1).max().unwrap();
+
+    if width.saturating_mul(height) > MAX_CELLS {
+        let (.., furthest) = points.iter().max_by_key(|(x, y, _)| *x.max(y)).unwrap();
+        return Err(ParseError::at(
+            input,
+            furthest,
+            format!("a dot on paper of at most {} cells", MAX_CELLS),
+        ));
+    }
+
+    // folding further out would make the paper bigger, not smaller
+    let folds = folds
+        .into_iter()
+        .map(|(fold, line)| match fold {
+            Fold::X(axis) if axis >= width => {
+                Err(ParseError::at(input, line, "a fold across the paper"))
+            }
+            Fold::Y(axis) if axis >= height => {
+                Err(ParseError::at(input, line, "a fold across the paper"))
+            }
+            fold => Ok(fold),
+        })
+        .collect::<Result<_, _>>()?;
+
+    let mut dots = Grid::new(width, height, false);
+    for (x, y, _) in points {
+        dots[Pos::new(x, y)] = true;
+    }
+
+    Ok((dots, folds))
+}
+
+fn part_one(dots: &Grid<bool>, fold: &Fold) -> usize {
+    count_dots(&perform_fold(dots, fold))
+}
+
+/// Folds the paper along every line, and crops it down to the dots so the
+/// letters they spell can be read off.
+fn part_two(dots: &Grid<bool>, folds: &[Fold]) -> Grid<char> {
+    let dots = folds
+        .iter()
+        .fold(dots.clone(), |dots, fold| perform_fold(&dots, fold));
+    let lit: Vec<_> = dots
+        .iter()
+        .filter(|(_, dot)| **dot)
+        .map(|(pos, _)| pos)
+        .collect();
+    let width = lit.iter().map(|pos| pos.x + 1).max().unwrap_or(0);
+    let height = lit.iter().map(|pos| pos.y + 1).max().unwrap_or(0);
+
+    Grid::from_fn(width, height, |pos| if dots[pos] { 'X' } else { '.' })
+}
+
+/// The paper before each fold, with the line it's about to be folded along
+/// drawn in, and once more when it's all folded up.
+pub(crate) fn frames(input: &str) -> Result<Frames, ParseError> {
+    let (dots, folds) = load_input(input)?;
+    let mut folds = folds.into_iter();
+    let mut paper = Some(dots);
+
+    let frames = iter::from_fn(move || {
+        let dots = paper.take()?;
+        let fold = folds.next();
+
+        let caption = match fold {
+            Some(Fold::X(axis)) => format!("folding along x={}", axis),
+            Some(Fold::Y(axis)) => format!("folding along y={}", axis),
+            None => String::from("folded"),
+        };
+        let cells = Grid::from_fn(dots.width(), dots.height(), |pos| match fold {
+            _ if dots[pos] => Cell::colored('#', Color::BrightWhite),
+            Some(Fold::X(axis)) if pos.x == axis => Cell::colored('|', Color::Red),
+            Some(Fold::Y(axis)) if pos.y == axis => Cell::colored('-', Color::Red),
+            _ => Cell::plain('.'),
+        });
+        let frame = Frame {
+            caption: format!("{}: {} dots", caption, count_dots(&dots)),
+            cells,
+        };
+
+        paper = fold.map(|fold| perform_fold(&dots, &fold));
+        Some(frame)
+    });
+
+    Ok(Box::new(frames))
+}
+
+/// The dots before any folding, and the letters they spell once folded.
+pub(crate) fn pictures(input: &str) -> Result<Vec<Picture>, ParseError> {
+    let (dots, folds) = load_input(input)?;
+    let folded = part_two(&dots, &folds).map(|c| *c == 'X');
+
+    Ok(vec![
+        Picture::ink("paper", &dots),
+        Picture::ink("folded", &folded),
+    ])
+}
+
+/// The paper, folded a line at a time.
+struct Origami {
+    dots: Grid<bool>,
+    folds: Vec<Fold>,
+    folded: usize,
+}
+
+impl Explorer for Origami {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("step", "make the next fold, or the next <n>"),
+            ("show", "print the paper"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "step" => {
+                let count = repl::count(args)?;
+                let left = &self.folds[self.folded..];
+                if left.is_empty() {
+                    return Err(String::from("the paper is folded all the way"));
+                }
+
+                let mut reply = vec![];
+                for fold in &left[..count.min(left.len())] {
+                    self.dots = perform_fold(&self.dots, fold);
+                    self.folded += 1;
+                    let line = match fold {
+                        Fold::X(axis) => format!("x={}", axis),
+                        Fold::Y(axis) => format!("y={}", axis),
+                    };
+                    reply.push(format!(
+                        "folded along {}: {} dots",
+                        line,
+                        count_dots(&self.dots)
+                    ));
+                }
+                Ok(reply.join("\n"))
+            }
+            _ => Ok(self
+                .dots
+                .map(|dot| if *dot { '#' } else { '.' })
+                .to_string()),
+        }
+    }
+}
+
+pub(crate) fn explore(input: &str) -> Result<Box<dyn Explorer>, ParseError> {
+    let (dots, folds) = load_input(input)?;
+
+    Ok(Box::new(Origami {
+        dots,
+        folds,
+        folded: 0,
+    }))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const INPUT: &'static str = include_str!("day13.txt");
+
+    type Input = (Grid<bool>, Vec<Fold>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one((dots, folds): &Self::Input) -> impl fmt::Display {
+        part_one(dots, &folds[0])
+    }
+
+    // Starts on a fresh line so the letters line up when printed after a label.
+    fn part_two((dots, folds): &Self::Input) -> impl fmt::Display {
+        format!("\n{}", part_two(dots, folds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, fixture_tests};
+
+    fixture_tests!(Day13);
+
+    #[test]
+    fn test_full_part_one_sample() {
+        let (dots, folds) = load_input(&fixtures::input(13, "sample")).unwrap();
+
+        let folded = perform_fold(&dots, &folds[0]);
+        let folded = perform_fold(&folded, &folds[1]);
+
+        assert_eq!(count_dots(&folded), 16);
+    }
+
+    #[test]
+    fn test_bad_fold() {
+        let err = load_input("6,10\n0,14\n\nfold along y=7\nfold across x=5").unwrap_err();
+
+        assert_eq!((err.line, err.column), (5, 1));
+        assert_eq!(err.expected, "`fold along `");
+
+        let err = load_input("6,10\n\nfold along z=7").unwrap_err();
+
+        assert_eq!((err.line, err.column), (3, 12));
+    }
+
+    #[test]
+    fn test_explore() {
+        let mut origami = explore(&fixtures::input(13, "sample")).unwrap();
+
+        assert_eq!(
+            origami.run("step", &["5"]),
+            Ok(String::from(
+                "folded along y=7: 17 dots\nfolded along x=5: 16 dots"
+            ))
+        );
+        assert!(origami.run("step", &[]).is_err());
+        assert!(origami
+            .run("show", &[])
+            .unwrap()
+            .starts_with("#####\n#...#"));
+    }
+
+    #[test]
+    fn test_paper_too_big() {
+        let err = load_input("6,10\n99999999,99999999\n\nfold along y=7").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = load_input("6,10\n0,14\n\nfold along x=700").unwrap_err();
+
+        assert_eq!((err.line, err.column), (4, 1));
+        assert_eq!(err.expected, "a fold across the paper");
+    }
+}
@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{debug, ParseError, Solution};
+
+type Rule = ((char, char), char);
+
+fn load_rule(input: &str, line: &str) -> Result<Rule, ParseError> {
+    let (pair, production) = line
+        .split_once(" -> ")
+        .ok_or_else(|| ParseError::after(input, line, "` -> `"))?;
+
+    let mut pair_chars = pair.chars();
+    let mut production_chars = production.chars();
+
+    match (
+        pair_chars.next(),
+        pair_chars.next(),
+        pair_chars.next(),
+        production_chars.next(),
+        production_chars.next(),
+    ) {
+        (Some(a), Some(b), None, Some(c), None) => Ok(((a, b), c)),
+        (_, _, None, ..) => Err(ParseError::at(input, production, "a single element")),
+        _ => Err(ParseError::at(input, pair, "a pair of elements")),
+    }
+}
+
+fn load_input(input: &str) -> Result<(Vec<char>, Vec<Rule>), ParseError> {
+    let mut lines = input.lines();
+
+    let template: Vec<_> = lines
+        .next()
+        .filter(|line| !line.is_empty())
+        .ok_or_else(|| ParseError::at(input, input, "a polymer template"))?
+        .chars()
+        .collect();
+
+    if let Some(line) = lines.next() {
+        if !line.is_empty() {
+            return Err(ParseError::at(input, line, "a blank line"));
+        }
+    }
+
+    let rules: Vec<_> = lines
+        .filter(|line| !line.is_empty())
+        .map(|line| load_rule(input, line))
+        .collect::<Result<_, _>>()?;
+
+    Ok((template, rules))
+}
+
+struct PairingCharVec<'a> {
+    vec: &'a Vec<char>,
+    index: usize,
+}
+
+impl<'a> PairingCharVec<'a> {
+    fn new(vec: &'a Vec<char>) -> PairingCharVec<'a> {
+        PairingCharVec { vec, index: 0 }
+    }
+}
+
+impl<'a> Iterator for PairingCharVec<'a> {
+    type Item = (char, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.vec.len() - 1 {
+            let item = (self.vec[self.index], self.vec[self.index + 1]);
+            self.index += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+}
+
+fn perform_step(template: Vec<char>, rules: &[Rule]) -> Vec<char> {
+    let pairing_iter = PairingCharVec::new(&template);
+
+    let mut mapped: Vec<_> = pairing_iter
+        .flat_map(
+            |(a, b)| match rules.iter().find(|((r_a, r_b), _)| *r_a == a && *r_b == b) {
+                Some(((_, _), prod)) => {
+                    vec![a, *prod]
+                }
+                None => {
+                    vec![a]
+                }
+            },
+        )
+        .collect();
+
+    mapped.push(template[template.len() - 1]);
+
+    mapped
+}
+
+fn part_one(template: &[char], rules: &[Rule], steps: usize) -> usize {
+    let mut polymer = template.to_vec();
+
+    for _ in 0..steps {
+        polymer = perform_step(polymer, rules);
+    }
+
+    let mut counts = HashMap::new();
+    for c in &polymer {
+        let c_count = counts.entry(c).or_insert(0);
+        *c_count += 1;
+    }
+
+    let mut max: Option<usize> = None;
+    let mut min: Option<usize> = None;
+    for (_, count) in counts {
+        if let Some(max_size) = max {
+            if count > max_size {
+                max = Some(count);
+            }
+        } else {
+            max = Some(count);
+        }
+
+        if let Some(min_size) = min {
+            if count < min_size {
+                min = Some(count);
+            }
+        } else {
+            min = Some(count);
+        }
+    }
+
+    max.unwrap() - min.unwrap()
+}
+
+#[allow(dead_code)]
+struct MemoedRule {
+    rule: Rule,
+    after_steps: usize,
+    produces: Vec<char>,
+    counts: HashMap<char, usize>,
+}
+
+#[allow(dead_code)]
+fn build_productions_to(rules: &[Rule], steps: usize) -> Vec<MemoedRule> {
+    rules
+        .iter()
+        .map(|rule| {
+            let mut produces = vec![rule.0 .0, rule.0 .1];
+            for _ in 0..steps {
+                produces = perform_step(produces, rules);
+            }
+            let mut counts = HashMap::new();
+            for c in &produces {
+                let c_count = counts.entry(*c).or_insert(0);
+                *c_count += 1;
+            }
+
+            debug!("produced for rule {:?}", rule);
+
+            MemoedRule {
+                rule: *rule,
+                after_steps: steps,
+                produces,
+                counts,
+            }
+        })
+        .collect()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const INPUT: &'static str = include_str!("day14.txt");
+
+    type Input = (Vec<char>, Vec<Rule>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one((template, rules): &Self::Input) -> impl fmt::Display {
+        part_one(template, rules, 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, fixture_tests};
+    use crate::prop;
+
+    fixture_tests!(Day14);
+
+    type Polymerization = (Vec<char>, Vec<Rule>, u32);
+
+    fn shrink_polymerization((template, rules, steps): &Polymerization) -> Vec<Polymerization> {
+        let shrink_element = |element: &char| {
+            if *element > 'A' {
+                vec!['A']
+            } else {
+                Vec::new()
+            }
+        };
+
+        let shorter_templates = prop::shrink_vec(template, shrink_element)
+            .into_iter()
+            .map(|template| (template, rules.clone(), *steps));
+        let fewer_rules = prop::shrink_vec(rules, |(pair, production)| {
+            shrink_element(production)
+                .into_iter()
+                .map(|production| (*pair, production))
+                .collect()
+        })
+        .into_iter()
+        .map(|rules| (template.clone(), rules, *steps));
+        let fewer_steps = prop::shrink_number(*steps)
+            .into_iter()
+            .map(|steps| (template.clone(), rules.clone(), steps));
+
+        shorter_templates
+            .chain(fewer_rules)
+            .chain(fewer_steps)
+            .collect()
+    }
+
+    /// The polymer `template` grows into, put together from what each of its
+    /// pairs is memoized to produce. Neighbouring productions share the
+    /// element between them, so it's only taken from one.
+    fn polymer_from_memo(template: &[char], memo: &[MemoedRule]) -> Vec<char> {
+        let mut polymer = Vec::new();
+
+        for pair in template.windows(2) {
+            match memo
+                .iter()
+                .find(|memoed| memoed.rule.0 == (pair[0], pair[1]))
+            {
+                Some(memoed) => polymer.extend(&memoed.produces[..memoed.produces.len() - 1]),
+                None => polymer.push(pair[0]),
+            }
+        }
+        polymer.extend(template.last());
+
+        polymer
+    }
+
+    #[test]
+    fn test_simulation_and_memo_agree() {
+        let polymer = prop::generated(14, 8, load_input);
+
+        prop::check(
+            |rng| {
+                let (template, rules) = polymer(rng);
+                (template, rules, rng.between(0, 6) as u32)
+            },
+            shrink_polymerization,
+            |(template, rules, steps)| {
+                if template.is_empty() {
+                    return Ok(());
+                }
+
+                let mut simulated = template.clone();
+                for _ in 0..*steps {
+                    simulated = perform_step(simulated, rules);
+                }
+                let memo = build_productions_to(rules, *steps as usize);
+
+                prop::agree(simulated, polymer_from_memo(template, &memo))
+            },
+        );
+    }
+
+    #[test]
+    fn test_productions_count_what_they_produce() {
+        let (_template, rules) = load_input(&fixtures::input(14, "sample")).unwrap();
+
+        for memo in build_productions_to(&rules, 10) {
+            let ((first, last), _) = memo.rule;
+
+            assert_eq!(memo.produces.len(), (1 << 10) + 1);
+            assert_eq!((memo.produces[0], memo.produces[1 << 10]), (first, last));
+            for (c, count) in &memo.counts {
+                assert_eq!(memo.produces.iter().filter(|p| *p == c).count(), *count);
+            }
+            assert_eq!(memo.counts.values().sum::<usize>(), memo.produces.len());
+        }
+    }
+}
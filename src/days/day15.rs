@@ -0,0 +1,145 @@
+use std::fmt;
+
+use crate::export::{Picture, Rgb};
+use crate::grid::{Grid, Pos};
+use crate::search::{self, Path};
+use crate::{debug, trace, ParseError, Solution};
+
+/// The way from the top left corner to the bottom right with the lowest
+/// total risk.
+fn safest_path(grid: &Grid<u32>) -> Path<Pos, u32> {
+    let target = Pos::new(grid.width() - 1, grid.height() - 1);
+    let distance = |pos: &Pos| (target.x - pos.x + target.y - pos.y) as u32;
+
+    // every position risks at least 1, so the distance left never overestimates
+    let path = search::astar(
+        Pos::new(0, 0),
+        |pos| {
+            grid.neighbours4(*pos)
+                .map(|adj_pos| (adj_pos, grid[adj_pos]))
+        },
+        distance,
+        |pos| *pos == target,
+    )
+    .expect("the corners of a grid are always connected");
+
+    log_path(&path);
+    path
+}
+
+fn log_path(path: &Path<Pos, u32>) {
+    debug!(
+        "safest path takes {} steps for a total risk of {}",
+        path.nodes.len() - 1,
+        path.cost
+    );
+    trace!(
+        "safest path: {}",
+        path.nodes
+            .iter()
+            .map(Pos::to_string)
+            .collect::<Vec<_>>()
+            .join(" ")
+    );
+}
+
+/// The full cave: the grid tiled five times each way, with risks rising by
+/// one per tile right or down and wrapping from 9 back to 1.
+fn full_map(grid: &Grid<u32>) -> Grid<u32> {
+    let (width, height) = (grid.width(), grid.height());
+
+    Grid::from_fn(width * 5, height * 5, |pos| {
+        let tile = (pos.x / width + pos.y / height) as u32;
+        let risk = grid[Pos::new(pos.x % width, pos.y % height)] + tile;
+        (risk - 1) % 9 + 1
+    })
+}
+
+fn part_one(grid: &Grid<u32>) -> u32 {
+    safest_path(grid).cost
+}
+
+fn part_two(grid: &Grid<u32>) -> u32 {
+    safest_path(&full_map(grid)).cost
+}
+
+fn load_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input, input, "a risk from 1 to 9", |c| {
+        c.to_digit(10).filter(|risk| *risk > 0)
+    })
+}
+
+/// The cave shaded by risk, darker where it's riskier, with the safest path
+/// through it in red.
+pub(crate) fn pictures(input: &str) -> Result<Vec<Picture>, ParseError> {
+    let grid = load_input(input)?;
+    let path = safest_path(&grid);
+    let cave = Picture {
+        name: "safest-path",
+        pixels: grid.map(|risk| Rgb::gray(9 - risk.min(&9), 8)),
+    };
+
+    Ok(vec![cave.highlight(&path.nodes, Rgb::RED)])
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const INPUT: &'static str = include_str!("day15.txt");
+
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(grid: &Self::Input) -> impl fmt::Display {
+        part_one(grid)
+    }
+
+    fn part_two(grid: &Self::Input) -> impl fmt::Display {
+        part_two(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, fixture_tests};
+
+    fixture_tests!(Day15);
+
+    #[test]
+    fn test_safest_path() {
+        let input = load_input(&fixtures::input(15, "sample")).unwrap();
+        let path = safest_path(&input);
+
+        assert_eq!(path.cost, 40);
+        assert_eq!(path.nodes.first(), Some(&Pos::new(0, 0)));
+        assert_eq!(path.nodes.last(), Some(&Pos::new(9, 9)));
+        assert_eq!(full_map(&input).width(), 50);
+    }
+
+    #[test]
+    fn test_zero_risk() {
+        let err = load_input("19\n10").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.expected, "a risk from 1 to 9");
+    }
+
+    #[test]
+    fn test_pictures() {
+        let pictures = pictures(&fixtures::input(15, "sample")).unwrap();
+        let pixels = &pictures[0].pixels;
+
+        assert_eq!(pixels[Pos::new(0, 0)], Rgb::RED);
+        assert_eq!(pixels[Pos::new(9, 9)], Rgb::RED);
+        // the sample's safest path takes 18 steps
+        assert_eq!(
+            pixels.cells().filter(|pixel| **pixel == Rgb::RED).count(),
+            19
+        );
+    }
+}
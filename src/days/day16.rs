@@ -0,0 +1,440 @@
+use std::fmt;
+
+use colored::*;
+
+use crate::{ParseError, Solution};
+
+/// Reads the hex digits of the transmission into bytes, along with where in
+/// the input each digit is, so errors can point at the digit a bit came from.
+fn load_bytes(input: &str) -> Result<(Vec<u8>, Vec<&str>), ParseError> {
+    let mut bytes = vec![];
+    let mut digits = vec![];
+
+    for (i, c) in input.char_indices().filter(|(_, c)| !c.is_whitespace()) {
+        let c = c
+            .to_digit(16)
+            .ok_or_else(|| ParseError::at(input, &input[i..], "a hexadecimal digit"))?;
+        if digits.len() % 2 == 0 {
+            bytes.push((c << 4) as u8);
+        } else {
+            let last_byte_index = bytes.len() - 1;
+            bytes[last_byte_index] |= c as u8;
+        }
+        digits.push(&input[i..i + 1]);
+    }
+
+    Ok((bytes, digits))
+}
+
+fn load_input(input: &str) -> Result<Packet, ParseError> {
+    let (bytes, digits) = load_bytes(input)?;
+
+    Parser::new(input, &bytes, &digits).parse_packet()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Operation {
+    Sum,
+    Product,
+    Min,
+    Max,
+    Greater,
+    Less,
+    Equal,
+}
+
+#[derive(Debug)]
+enum PacketPayload {
+    Literal {
+        value: u64,
+    },
+    Operator {
+        operation: Operation,
+        packets: Vec<Packet>,
+    },
+}
+
+#[derive(Debug)]
+pub struct Packet {
+    version: u8,
+    payload: PacketPayload,
+}
+
+impl Packet {
+    fn sum_versions(&self) -> u32 {
+        match &self.payload {
+            PacketPayload::Literal { .. } => self.version as u32,
+            PacketPayload::Operator { packets, .. } => {
+                let children_sum: u32 = packets.iter().map(|packet| packet.sum_versions()).sum();
+                self.version as u32 + children_sum
+            }
+        }
+    }
+
+    fn evaluate(&self) -> u64 {
+        use Operation::*;
+
+        match &self.payload {
+            PacketPayload::Literal { value } => *value,
+            PacketPayload::Operator { operation, packets } => match operation {
+                Sum => packets
+                    .iter()
+                    .fold(0, |acc, packet| acc + packet.evaluate()),
+                Product => packets
+                    .iter()
+                    .fold(1, |acc, packet| acc * packet.evaluate()),
+                Min => packets
+                    .iter()
+                    .map(|packet| packet.evaluate())
+                    .min()
+                    .unwrap(),
+                Max => packets
+                    .iter()
+                    .map(|packet| packet.evaluate())
+                    .max()
+                    .unwrap(),
+                Greater => {
+                    let a = packets[0].evaluate();
+                    let b = packets[1].evaluate();
+                    if a > b {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                Less => {
+                    let a = packets[0].evaluate();
+                    let b = packets[1].evaluate();
+                    if a < b {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                Equal => {
+                    let a = packets[0].evaluate();
+                    let b = packets[1].evaluate();
+                    if a == b {
+                        1
+                    } else {
+                        0
+                    }
+                }
+            },
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    bytes: &'a [u8],
+    /// Where in the input each hex digit of `bytes` came from.
+    digits: &'a [&'a str],
+    bit_index: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str, bytes: &'a [u8], digits: &'a [&'a str]) -> Parser<'a> {
+        Parser {
+            input,
+            bytes,
+            digits,
+            bit_index: 0,
+        }
+    }
+
+    #[allow(dead_code)]
+    fn print(&self) {
+        for (byte_index, byte) in self.bytes.iter().enumerate() {
+            for bit_index in 0..8 {
+                let bit = (byte_index * 8) + bit_index;
+                let mask = 0x1 << (7 - bit_index);
+                let bit_is_set = byte & mask != 0;
+                if self.bit_index == bit {
+                    if bit_is_set {
+                        print!("{}", "1".red());
+                    } else {
+                        print!("{}", "0".red());
+                    }
+                } else {
+                    if bit_is_set {
+                        print!("1");
+                    } else {
+                        print!("0");
+                    }
+                }
+            }
+        }
+        println!();
+    }
+
+    /// Points at the hex digit holding the bit at `index`, or just past the
+    /// last one if the transmission ends before it.
+    fn error_at(&self, index: usize, expected: impl Into<String>) -> ParseError {
+        match (self.digits.get(index / 4), self.digits.last()) {
+            (Some(digit), _) => ParseError::at(self.input, digit, expected),
+            (None, Some(last)) => ParseError::after(self.input, last, expected),
+            (None, None) => ParseError::at(self.input, self.input, expected),
+        }
+    }
+
+    fn bit_set_at(&self, index: usize) -> bool {
+        let byte_offset = index / 8;
+        let bit_offset = index % 8;
+
+        let byte = self.bytes[byte_offset];
+        let mask = 0x1 << (7 - bit_offset);
+
+        byte & mask != 0
+    }
+
+    fn read_n_bits(&mut self, n: usize, expected: &str) -> Result<u64, ParseError> {
+        // the last byte is padded out with zeroes when there's an odd number
+        // of digits, which aren't part of the transmission
+        if self.bit_index + n > self.digits.len() * 4 {
+            return Err(self.error_at(self.bit_index + n, expected));
+        }
+
+        let mut read = 0;
+        for i in 0..n {
+            if self.bit_set_at(self.bit_index + i) {
+                // TODO(MATT): peel bits in larger operations, not one at a time
+                let mask = 0x1 << ((n - 1) - i);
+                read |= mask;
+            }
+        }
+        self.bit_index += n;
+
+        Ok(read)
+    }
+
+    fn parse_literal_payload(&mut self) -> Result<u64, ParseError> {
+        let mut literal: u64 = 0;
+
+        loop {
+            let chunk_start = self.bit_index;
+            let is_last = self.read_n_bits(1, "another group of the literal")? == 0;
+            let chunk = self.read_n_bits(4, "another group of the literal")?;
+
+            if literal >> 60 != 0 {
+                return Err(self.error_at(chunk_start, "a literal that fits in 64 bits"));
+            }
+            literal = literal << 4 | chunk;
+
+            if is_last {
+                return Ok(literal);
+            }
+        }
+    }
+
+    fn parse_operator_payload(&mut self) -> Result<Vec<Packet>, ParseError> {
+        let mut packets = vec![];
+
+        let parse_sub_packets_by_bits = self.read_n_bits(1, "a length type")? == 0;
+
+        if parse_sub_packets_by_bits {
+            let length_start = self.bit_index;
+            let bit_count = self.read_n_bits(15, "a length in bits")? as usize;
+            let start = self.bit_index;
+            while self.bit_index - start < bit_count {
+                packets.push(self.parse_packet()?);
+            }
+            if self.bit_index - start != bit_count {
+                return Err(self.error_at(
+                    length_start,
+                    format!("sub-packets that fill {} bits exactly", bit_count),
+                ));
+            }
+        } else {
+            let packet_count = self.read_n_bits(11, "a number of sub-packets")?;
+            for _ in 0..packet_count {
+                packets.push(self.parse_packet()?);
+            }
+        }
+
+        Ok(packets)
+    }
+
+    fn parse_packet(&mut self) -> Result<Packet, ParseError> {
+        use Operation::*;
+
+        let start = self.bit_index;
+        let version = self.read_n_bits(3, "a packet version")? as u8;
+        let packet_type = self.read_n_bits(3, "a packet type")? as u8;
+
+        let operation = match packet_type {
+            0 => Sum,
+            1 => Product,
+            2 => Min,
+            3 => Max,
+            4 => {
+                return Ok(Packet {
+                    version,
+                    payload: PacketPayload::Literal {
+                        value: self.parse_literal_payload()?,
+                    },
+                })
+            }
+            5 => Greater,
+            6 => Less,
+            _ => Equal,
+        };
+        let packets = self.parse_operator_payload()?;
+
+        let compares = matches!(operation, Greater | Less | Equal);
+        if compares && packets.len() != 2 {
+            return Err(self.error_at(start, "exactly two sub-packets to compare"));
+        }
+        if packets.is_empty() {
+            return Err(self.error_at(start, "at least one sub-packet"));
+        }
+
+        Ok(Packet {
+            version,
+            payload: PacketPayload::Operator { operation, packets },
+        })
+    }
+}
+
+fn part_one(packet: &Packet) -> u32 {
+    packet.sum_versions()
+}
+
+fn part_two(packet: &Packet) -> u64 {
+    packet.evaluate()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const INPUT: &'static str = include_str!("day16.txt");
+
+    type Input = Packet;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(packet: &Self::Input) -> impl fmt::Display {
+        part_one(packet)
+    }
+
+    fn part_two(packet: &Self::Input) -> impl fmt::Display {
+        part_two(packet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::fixture_tests;
+
+    fixture_tests!(Day16);
+
+    #[test]
+    fn test_parse_literal() {
+        let input = "D2FE28";
+        let packet = load_input(input).unwrap();
+
+        assert_eq!(packet.version, 6);
+
+        if let PacketPayload::Literal { value } = packet.payload {
+            assert_eq!(value, 2021);
+        } else {
+            panic!("didn't parse a literal");
+        }
+    }
+
+    #[test]
+    fn test_parse_bit_length_operator() {
+        let input = "38006F45291200";
+        let packet = load_input(input).unwrap();
+
+        assert_eq!(packet.version, 1);
+
+        if let PacketPayload::Operator { operation, packets } = packet.payload {
+            assert_eq!(operation, Operation::Less);
+            assert_eq!(packets.len(), 2);
+        } else {
+            panic!("didn't parse an operator");
+        }
+    }
+
+    #[test]
+    fn test_parse_packet_count_operator() {
+        let input = "EE00D40C823060";
+        let packet = load_input(input).unwrap();
+
+        assert_eq!(packet.version, 7);
+
+        if let PacketPayload::Operator { operation, packets } = packet.payload {
+            assert_eq!(operation, Operation::Max);
+            assert_eq!(packets.len(), 3);
+        } else {
+            panic!("didn't parse an operator");
+        }
+    }
+
+    #[test]
+    fn test_packet_sums() {
+        let input = load_input("8A004A801A8002F478").unwrap();
+        assert_eq!(part_one(&input), 16);
+
+        let input = load_input("620080001611562C8802118E34").unwrap();
+        assert_eq!(part_one(&input), 12);
+
+        let input = load_input("C0015000016115A2E0802F182340").unwrap();
+        assert_eq!(part_one(&input), 23);
+
+        let input = load_input("A0016C880162017C3686B18A3D4780").unwrap();
+        assert_eq!(part_one(&input), 31);
+    }
+
+    #[test]
+    fn test_part_two_samples() {
+        let input = load_input("C200B40A82").unwrap();
+        assert_eq!(part_two(&input), 3);
+
+        let input = load_input("04005AC33890").unwrap();
+        assert_eq!(part_two(&input), 54);
+
+        let input = load_input("880086C3E88112").unwrap();
+        assert_eq!(part_two(&input), 7);
+
+        let input = load_input("CE00C43D881120").unwrap();
+        assert_eq!(part_two(&input), 9);
+
+        let input = load_input("D8005AC2A8F0").unwrap();
+        assert_eq!(part_two(&input), 1);
+
+        let input = load_input("F600BC2D8F").unwrap();
+        assert_eq!(part_two(&input), 0);
+
+        let input = load_input("9C005AC2F8F0").unwrap();
+        assert_eq!(part_two(&input), 0);
+
+        let input = load_input("9C0141080250320F1802104A08").unwrap();
+        assert_eq!(part_two(&input), 1);
+    }
+
+    #[test]
+    fn test_bad_packets() {
+        // a literal cut off after its first group
+        let err = load_input("D2F").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 4));
+        assert_eq!(err.expected, "another group of the literal");
+
+        // a less than packet with a single literal in it
+        let err = load_input("1A004408").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.expected, "exactly two sub-packets to compare");
+
+        // a literal with 17 groups
+        let err = load_input("12318C6318C6318C6318C42").unwrap_err();
+        assert_eq!(err.expected, "a literal that fits in 64 bits");
+
+        let err = load_input("").unwrap_err();
+        assert_eq!(err.expected, "a packet version");
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt;
+
+use crate::{parse, ParseError, Solution};
+
+#[derive(Debug)]
+pub struct Target {
+    x: (i64, i64),
+    y: (i64, i64),
+}
+
+fn load_range(input: &str, range: &str) -> Result<(i64, i64), ParseError> {
+    let (low, high) = range
+        .split_once("..")
+        .ok_or_else(|| ParseError::after(input, range, "`..`"))?;
+    let low: i64 = parse::field(input, low, "a number")?;
+    let high: i64 = parse::field(input, high, "a number")?;
+
+    if low > high {
+        return Err(ParseError::at(input, range, "a range from low to high"));
+    }
+
+    Ok((low, high))
+}
+
+fn load_input(input: &str) -> Result<Target, ParseError> {
+    let line = input.lines().next().unwrap_or(input);
+    let ranges = parse::prefix(input, line, "target area: x=")?;
+
+    let (xs, ys) = ranges
+        .split_once(", y=")
+        .ok_or_else(|| ParseError::after(input, ranges, "`, y=`"))?;
+    let (xmin, xmax) = load_range(input, xs)?;
+    let (ymax, ymin) = load_range(input, ys)?;
+
+    Ok(Target {
+        x: (xmin, xmax),
+        y: (ymin, ymax),
+    })
+}
+
+fn tri_num(num: i64) -> i64 {
+    (num * (num + 1)) / 2
+}
+
+fn derive_tri(num: i64) -> i64 {
+    let squared = (8 * num) + 1;
+    (((squared as f64).sqrt() as i64) + 1) / 2
+}
+
+fn determine_high_vel(target: &Target) -> (i64, i64) {
+    let mut x = derive_tri(target.x.0);
+
+    // how far x gets only grows with the velocity, so if the first one to
+    // reach the target overshoots it, none of them stop over it
+    while tri_num(x) < target.x.0 {
+        x += 1;
+    }
+
+    let mut y = if target.y.1 < 0 {
+        -target.y.1
+    } else {
+        target.y.1
+    };
+    y -= 1;
+
+    (x, y)
+}
+
+fn part_one(target: &Target) -> i64 {
+    let high_vel = determine_high_vel(target);
+    tri_num(high_vel.1)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const INPUT: &'static str = include_str!("day17.txt");
+
+    type Input = Target;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(target: &Self::Input) -> impl fmt::Display {
+        part_one(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::fixture_tests;
+
+    fixture_tests!(Day17);
+
+    #[test]
+    fn test_reversed_ranges() {
+        for (input, column) in [
+            ("target area: x=2488..285, y=-85..-56", 16),
+            ("target area: x=20..30, y=-5..-10", 26),
+        ] {
+            let err = load_input(input).unwrap_err();
+
+            assert_eq!(
+                (err.line, err.column, err.expected.as_str()),
+                (1, column, "a range from low to high")
+            );
+        }
+    }
+
+    #[test]
+    fn test_no_x_velocity_stops_over_the_target() {
+        let target = load_input("target area: x=4..5, y=-10..-5").unwrap();
+
+        assert_eq!(part_one(&target), 45);
+    }
+}
+
+//      v, p
+//  3 : 3, 0  2, 3  1, 5  0, 6
+//  3 + 2 + 1 = 6
+//  6 / 4 = 1
+//
+//  4 : 4, 0  3, 4  2, 7  1, 9  0,10
+//  4 + 3 + 2 + 1 = 10
+//  10 / 4 = 2
+//
@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+
+use crate::{parse, ParseError, Solution};
+
+type Point = (i32, i32, i32);
+
+fn load_header(input: &str, line: Option<&str>) -> Result<(), ParseError> {
+    let line = line.ok_or_else(|| ParseError::after(input, input, "a scanner header"))?;
+    let rest = parse::prefix(input, line, "--- scanner ")?;
+    // the empty rest is taken from the end of the line, so an error can point there
+    let (number, rest) = rest.split_once(' ').unwrap_or((rest, &rest[rest.len()..]));
+
+    parse::field::<usize>(input, number, "a scanner number")?;
+    if rest != "---" {
+        return Err(ParseError::at(input, rest, "`---`"));
+    }
+
+    Ok(())
+}
+
+fn load_input(input: &str) -> Result<Vec<Vec<Point>>, ParseError> {
+    let mut scanners = vec![];
+    let mut current_scanner = vec![];
+
+    let mut lines = input.lines();
+    load_header(input, lines.next())?;
+
+    while let Some(line) = lines.next() {
+        if line.is_empty() {
+            scanners.push(current_scanner);
+            current_scanner = vec![];
+
+            if let Some(header) = lines.next() {
+                load_header(input, Some(header))?;
+            }
+        } else {
+            let mut split = line.split(',');
+            let x = parse::next(input, line, &mut split, "an x coordinate")?;
+            let y = parse::next(input, line, &mut split, "`,`")?;
+            let z = parse::next(input, line, &mut split, "`,`")?;
+            current_scanner.push((
+                parse::field(input, x, "an x coordinate")?,
+                parse::field(input, y, "a y coordinate")?,
+                parse::field(input, z, "a z coordinate")?,
+            ));
+        }
+    }
+
+    if !current_scanner.is_empty() {
+        scanners.push(current_scanner);
+    }
+
+    Ok(scanners)
+}
+
+struct PointPair {
+    a: Point,
+    b: Point,
+    dist: Point,
+}
+
+fn distance(a: &Point, b: &Point) -> Point {
+    ((a.0 - b.0).abs(), (a.1 - b.1).abs(), (a.2 - b.2).abs())
+}
+
+fn distances(scanner: &[Point]) -> Vec<PointPair> {
+    let mut dist = vec![];
+
+    for x in 0..scanner.len() - 1 {
+        for y in (x + 1)..scanner.len() {
+            let a = scanner[x];
+            let b = scanner[y];
+
+            dist.push(PointPair {
+                a,
+                b,
+                dist: distance(&a, &b),
+            });
+        }
+    }
+
+    dist
+}
+
+#[allow(dead_code)]
+fn build_point_map(scanner_a: &[Point], scanner_b: &[Point]) -> HashMap<Point, Point> {
+    let a_distances = distances(scanner_a);
+    let b_distances = distances(scanner_b);
+
+    let mut point_map: HashMap<Point, Point> = HashMap::new();
+
+    for a_dist in &a_distances {
+        match b_distances.iter().find(|d| d.dist == a_dist.dist) {
+            Some(b_dist) => {
+                if let Some(other_a_dist) = a_distances
+                    .iter()
+                    .find(|d| d.dist != a_dist.dist && (a_dist.a == d.a || a_dist.a == d.b))
+                {
+                    if let Some(other_b_dist) =
+                        b_distances.iter().find(|d| d.dist == other_a_dist.dist)
+                    {
+                        if b_dist.a == other_b_dist.a || b_dist.a == other_b_dist.b {
+                            point_map.insert(a_dist.a, b_dist.a);
+                        } else if b_dist.b == other_b_dist.a || b_dist.b == other_b_dist.b {
+                            point_map.insert(a_dist.a, b_dist.b);
+                        } else {
+                            unreachable!();
+                        }
+                    }
+                }
+
+                if let Some(other_a_dist) = a_distances
+                    .iter()
+                    .find(|d| d.dist != a_dist.dist && (a_dist.b == d.a || a_dist.b == d.b))
+                {
+                    if let Some(other_b_dist) =
+                        b_distances.iter().find(|d| d.dist == other_a_dist.dist)
+                    {
+                        if b_dist.a == other_b_dist.a || b_dist.a == other_b_dist.b {
+                            point_map.insert(a_dist.b, b_dist.a);
+                        } else if b_dist.b == other_b_dist.a || b_dist.b == other_b_dist.b {
+                            point_map.insert(a_dist.b, b_dist.b);
+                        } else {
+                            unreachable!();
+                        }
+                    }
+                }
+            }
+            None => continue,
+        }
+    }
+
+    point_map
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const INPUT: &'static str = include_str!("day19.txt");
+
+    type Input = Vec<Vec<Point>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bad_header() {
+        let err = load_input("--- scanner 0").unwrap_err();
+
+        assert_eq!((err.line, err.column), (1, 14));
+        assert_eq!(err.expected, "`---`");
+    }
+}
@@ -0,0 +1,100 @@
+use std::fmt;
+
+use crate::{parse, ParseError, Solution};
+
+#[derive(Debug)]
+pub enum Mvmt {
+    Forward(i32),
+    Up(i32),
+    Down(i32),
+}
+
+fn part_one(depths: &[Mvmt]) -> i32 {
+    let mut hor = 0;
+    let mut ver = 0;
+
+    for mvmt in depths {
+        match mvmt {
+            Mvmt::Forward(amt) => hor += amt,
+            Mvmt::Up(amt) => ver -= amt,
+            Mvmt::Down(amt) => ver += amt,
+        }
+    }
+
+    hor * ver
+}
+
+fn part_two(depths: &[Mvmt]) -> i32 {
+    let mut hor = 0;
+    let mut ver = 0;
+    let mut aim = 0;
+
+    for mvmt in depths {
+        match mvmt {
+            Mvmt::Forward(amt) => {
+                hor += amt;
+                ver += aim * amt;
+            }
+            Mvmt::Up(amt) => aim -= amt,
+            Mvmt::Down(amt) => aim += amt,
+        }
+    }
+
+    hor * ver
+}
+
+fn load_input(input: &str) -> Result<Vec<Mvmt>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let mut split = line.split_whitespace();
+            let dir = parse::next(input, line, &mut split, "a movement")?;
+            let amt = parse::next(input, line, &mut split, "an amount")?;
+            let amt: i32 = parse::field(input, amt, "an amount")?;
+            match dir {
+                "forward" => Ok(Mvmt::Forward(amt)),
+                "up" => Ok(Mvmt::Up(amt)),
+                "down" => Ok(Mvmt::Down(amt)),
+                _ => Err(ParseError::at(input, dir, "`forward`, `up` or `down`")),
+            }
+        })
+        .collect()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = include_str!("day2.txt");
+
+    type Input = Vec<Mvmt>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(depths: &Self::Input) -> impl fmt::Display {
+        part_one(depths)
+    }
+
+    fn part_two(depths: &Self::Input) -> impl fmt::Display {
+        part_two(depths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::fixture_tests;
+
+    fixture_tests!(Day2);
+
+    #[test]
+    fn test_bad_movement() {
+        let err = load_input("forward 5\nsideways 2\n").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "`forward`, `up` or `down`");
+    }
+}
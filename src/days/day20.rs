@@ -0,0 +1,217 @@
+use std::fmt;
+use std::iter;
+
+use colored::Color;
+
+use crate::animate::{Cell, Frame, Frames};
+use crate::export::Picture;
+use crate::grid::{Grid, Pos};
+use crate::{ParseError, Solution};
+
+fn extend(image: &Grid<bool>, by: usize) -> Grid<bool> {
+    Grid::from_fn(
+        image.width() + by * 2,
+        image.height() + by * 2,
+        |pos| match (pos.x.checked_sub(by), pos.y.checked_sub(by)) {
+            (Some(x), Some(y)) => image.get(Pos::new(x, y)).copied().unwrap_or(false),
+            _ => false,
+        },
+    )
+}
+
+/// Enhances every pixel from the 3x3 square around it. Pixels past the edge
+/// of the image take the value of the edge, which stands in for the infinite
+/// image beyond it.
+fn map_by_algorithm(image: &Grid<bool>, algorithm: &[bool]) -> Grid<bool> {
+    Grid::from_fn(image.width(), image.height(), |pos| {
+        let mut index = 0;
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                let bit = image
+                    .step(pos, dx, dy)
+                    .map_or(image[pos], |adj_pos| image[adj_pos]);
+                index = (index << 1) | bit as usize;
+            }
+        }
+
+        algorithm[index]
+    })
+}
+
+fn lit_pixels(image: &Grid<bool>) -> usize {
+    image.cells().filter(|bit| **bit).count()
+}
+
+fn stray_pixels<'a>(input: &'a str, line: &'a str) -> impl Iterator<Item = ParseError> + 'a {
+    line.char_indices()
+        .filter(|(_, c)| *c != '#' && *c != '.')
+        .map(move |(i, _)| ParseError::at(input, &line[i..], "`#` or `.`"))
+}
+
+/// Every pixel that isn't one, an algorithm that isn't 512 long and every row
+/// of the image that's wider or narrower than the first, rather than just the
+/// first of them.
+pub(crate) fn validate(input: &str) -> Vec<ParseError> {
+    let mut problems = vec![];
+    let mut lines = input.lines().map(str::trim_end);
+
+    let algorithm = lines.next().unwrap_or("");
+    problems.extend(stray_pixels(input, algorithm));
+    if algorithm.chars().count() != 512 {
+        problems.push(ParseError::at(
+            input,
+            algorithm,
+            "an algorithm of 512 pixels",
+        ));
+    }
+
+    if let Some(line) = lines.next().filter(|line| !line.is_empty()) {
+        problems.push(ParseError::at(input, line, "a blank line"));
+    }
+
+    let mut rows: Vec<_> = lines.collect();
+    while rows.last() == Some(&"") {
+        rows.pop();
+    }
+    let Some(first) = rows.first() else {
+        problems.push(ParseError::after(input, input, "an image"));
+        return problems;
+    };
+    let width = first.chars().count();
+    for row in &rows {
+        problems.extend(stray_pixels(input, row));
+        if row.chars().count() != width {
+            problems.push(ParseError::at(
+                input,
+                row,
+                format!("a row of {} pixels, like the first", width),
+            ));
+        }
+    }
+
+    problems
+}
+
+fn load_input(input: &str) -> Result<(Vec<bool>, Grid<bool>), ParseError> {
+    let (algorithm_line, image) = input.split_once('\n').unwrap_or((input, ""));
+    let algorithm = Grid::pixels(input, algorithm_line)?;
+
+    if algorithm.width() != 512 {
+        return Err(ParseError::at(
+            input,
+            algorithm_line,
+            "an algorithm of 512 pixels",
+        ));
+    }
+
+    if let Some(line) = image.lines().next() {
+        if !line.is_empty() {
+            return Err(ParseError::at(input, line, "a blank line"));
+        }
+    }
+
+    if image.trim().is_empty() {
+        return Err(ParseError::after(input, input, "an image"));
+    }
+    let image = Grid::pixels(input, image)?;
+
+    Ok((algorithm.cells().copied().collect(), image))
+}
+
+fn map_by_algorithm_times(image: &Grid<bool>, algorithm: &[bool], times: usize) -> Grid<bool> {
+    let mut image = extend(image, times);
+
+    for _ in 0..times {
+        image = map_by_algorithm(&image, algorithm);
+    }
+
+    image
+}
+
+fn part_one(image: &Grid<bool>, algorithm: &[bool]) -> usize {
+    lit_pixels(&map_by_algorithm_times(image, algorithm, 2))
+}
+
+fn part_two(image: &Grid<bool>, algorithm: &[bool]) -> usize {
+    lit_pixels(&map_by_algorithm_times(image, algorithm, 50))
+}
+
+/// The image after each of the 50 passes of part two, with room around it to
+/// grow into.
+pub(crate) fn frames(input: &str) -> Result<Frames, ParseError> {
+    const PASSES: usize = 50;
+    let (algorithm, image) = load_input(input)?;
+
+    let frames = iter::successors(Some((0, extend(&image, PASSES))), move |(pass, image)| {
+        (*pass < PASSES).then(|| (pass + 1, map_by_algorithm(image, &algorithm)))
+    })
+    .map(|(pass, image)| Frame {
+        caption: format!("pass {}: {} lit", pass, lit_pixels(&image)),
+        cells: image.map(|lit| match lit {
+            true => Cell::colored('#', Color::BrightWhite),
+            false => Cell::plain('.'),
+        }),
+    });
+
+    Ok(Box::new(frames))
+}
+
+/// The image as it starts, and after the passes of each part.
+pub(crate) fn pictures(input: &str) -> Result<Vec<Picture>, ParseError> {
+    let (algorithm, image) = load_input(input)?;
+
+    Ok(vec![
+        Picture::ink("image", &image),
+        Picture::ink("enhanced-2", &map_by_algorithm_times(&image, &algorithm, 2)),
+        Picture::ink(
+            "enhanced-50",
+            &map_by_algorithm_times(&image, &algorithm, 50),
+        ),
+    ])
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const INPUT: &'static str = include_str!("day20.txt");
+
+    type Input = (Vec<bool>, Grid<bool>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one((algorithm, image): &Self::Input) -> impl fmt::Display {
+        part_one(image, algorithm)
+    }
+
+    fn part_two((algorithm, image): &Self::Input) -> impl fmt::Display {
+        part_two(image, algorithm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::fixture_tests;
+
+    fixture_tests!(Day20);
+
+    #[test]
+    fn test_validate() {
+        let problems: Vec<_> = validate("#.x\n\n#.\n#\n")
+            .into_iter()
+            .map(|problem| (problem.line, problem.column, problem.expected))
+            .collect();
+
+        assert_eq!(
+            problems,
+            [
+                (1, 3, String::from("`#` or `.`")),
+                (1, 1, String::from("an algorithm of 512 pixels")),
+                (4, 1, String::from("a row of 2 pixels, like the first")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+
+use crate::{parse, ParseError, Solution};
+
+fn load_position(input: &str, line: Option<&str>, player: usize) -> Result<usize, ParseError> {
+    let prefix = format!("Player {} starting position: ", player);
+    let line = line.ok_or_else(|| ParseError::after(input, input, format!("`{}`", prefix)))?;
+    let position = parse::prefix(input, line, &prefix)?;
+
+    match parse::field(input, position, "a position from 1 to 10")? {
+        position @ 1..=10 => Ok(position),
+        _ => Err(ParseError::at(input, position, "a position from 1 to 10")),
+    }
+}
+
+fn load_input(input: &str) -> Result<(usize, usize), ParseError> {
+    let mut lines = input.lines();
+
+    let player_one_pos = load_position(input, lines.next(), 1)?;
+    let player_two_pos = load_position(input, lines.next(), 2)?;
+
+    Ok((player_one_pos, player_two_pos))
+}
+
+fn part_one(mut pos_a: usize, mut pos_b: usize) -> usize {
+    let mut score_a = 0;
+    let mut score_b = 0;
+
+    let mut roles = 0;
+
+    loop {
+        let (curr_score, curr_pos) = if (roles / 3) % 2 == 0 {
+            (&mut score_a, &mut pos_a)
+        } else {
+            (&mut score_b, &mut pos_b)
+        };
+
+        let mut curr_role = 0;
+        for _ in 0..3 {
+            roles += 1;
+            let role_val = ((roles - 1) % 100) + 1;
+            curr_role += role_val;
+        }
+
+        *curr_pos = ((*curr_pos + curr_role - 1) % 10) + 1;
+        *curr_score += *curr_pos;
+
+        if *curr_score >= 1000 {
+            let (losing_score, _) = if (roles / 3) % 2 == 0 {
+                (score_a, pos_a)
+            } else {
+                (score_b, pos_b)
+            };
+
+            return losing_score * roles;
+        }
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const INPUT: &'static str = include_str!("day21.txt");
+
+    type Input = (usize, usize);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one((pos_a, pos_b): &Self::Input) -> impl fmt::Display {
+        part_one(*pos_a, *pos_b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::fixture_tests;
+
+    fixture_tests!(Day21);
+
+    #[test]
+    fn test_ten_is_a_position() {
+        let input = "Player 1 starting position: 10
+Player 2 starting position: 3
+";
+        assert_eq!(load_input(input).unwrap(), (10, 3));
+
+        let err = load_input("Player 1 starting position: 11\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 29));
+    }
+}
@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{parse, ParseError, Solution};
+
+/// How far from the origin a cuboid can reach, which keeps the volume of
+/// even the biggest one inside an `i64`.
+const MAX_COORD: i64 = 1_000_000;
+
+/// How far from the origin the initialization region reaches.
+const INIT_REGION: i64 = 50;
+
+/// A box of cubes, with both ends of each range included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid {
+    pub x: (i64, i64),
+    pub y: (i64, i64),
+    pub z: (i64, i64),
+}
+
+impl Cuboid {
+    /// The cube `radius` away from the origin in every direction.
+    fn around_origin(radius: i64) -> Cuboid {
+        Cuboid {
+            x: (-radius, radius),
+            y: (-radius, radius),
+            z: (-radius, radius),
+        }
+    }
+
+    fn volume(&self) -> i64 {
+        [self.x, self.y, self.z]
+            .iter()
+            .map(|(low, high)| high - low + 1)
+            .product()
+    }
+
+    /// The cubes in both cuboids, if there are any.
+    fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let overlap = |(a_low, a_high): (i64, i64), (b_low, b_high): (i64, i64)| {
+            let (low, high) = (a_low.max(b_low), a_high.min(b_high));
+            (low <= high).then_some((low, high))
+        };
+
+        Some(Cuboid {
+            x: overlap(self.x, other.x)?,
+            y: overlap(self.y, other.y)?,
+            z: overlap(self.z, other.z)?,
+        })
+    }
+}
+
+/// One step of the reboot, turning every cube in a cuboid on or off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub on: bool,
+    pub cuboid: Cuboid,
+}
+
+fn load_range(input: &str, text: &str, axis: &str) -> Result<(i64, i64), ParseError> {
+    let range = parse::prefix(input, text, &format!("{}=", axis))?;
+    let (low, high) = range
+        .split_once("..")
+        .ok_or_else(|| ParseError::after(input, range, "`..`"))?;
+
+    let expected = format!("a coordinate within {} of 0", MAX_COORD);
+    let coord = |text| {
+        parse::field::<i64>(input, text, &expected)
+            .ok()
+            .filter(|coord| coord.abs() <= MAX_COORD)
+            .ok_or_else(|| ParseError::at(input, text, expected.as_str()))
+    };
+    let (low, high) = (coord(low)?, coord(high)?);
+
+    if low > high {
+        return Err(ParseError::at(input, range, "a range from low to high"));
+    }
+
+    Ok((low, high))
+}
+
+fn load_input(input: &str) -> Result<Vec<Step>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (state, ranges) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::at(input, line, "`on` or `off`"))?;
+            let on = match state {
+                "on" => true,
+                "off" => false,
+                _ => return Err(ParseError::at(input, state, "`on` or `off`")),
+            };
+
+            let mut split = ranges.split(',');
+            let x = parse::next(input, line, &mut split, "an x range")?;
+            let y = parse::next(input, line, &mut split, "`,`")?;
+            let z = parse::next(input, line, &mut split, "`,`")?;
+            if let Some(extra) = split.next() {
+                return Err(ParseError::at(input, extra, "the end of the line"));
+            }
+
+            Ok(Step {
+                on,
+                cuboid: Cuboid {
+                    x: load_range(input, x, "x")?,
+                    y: load_range(input, y, "y")?,
+                    z: load_range(input, z, "z")?,
+                },
+            })
+        })
+        .collect()
+}
+
+/// Runs the reboot cube by cube within `radius` of the origin, ignoring the
+/// cubes further out.
+fn simulate(steps: &[Step], radius: i64) -> usize {
+    let side = (2 * radius + 1) as usize;
+    let index = |coord: i64| (coord + radius) as usize;
+    let mut cubes = vec![false; side * side * side];
+
+    for step in steps {
+        let Some(cuboid) = step.cuboid.intersection(&Cuboid::around_origin(radius)) else {
+            continue;
+        };
+
+        for x in cuboid.x.0..=cuboid.x.1 {
+            for y in cuboid.y.0..=cuboid.y.1 {
+                for z in cuboid.z.0..=cuboid.z.1 {
+                    cubes[(index(x) * side + index(y)) * side + index(z)] = step.on;
+                }
+            }
+        }
+    }
+
+    cubes.iter().filter(|on| **on).count()
+}
+
+/// Counts the cubes left on by keeping a tally of signed cuboids: each step
+/// cancels out its overlap with every cuboid counted so far, then adds
+/// itself if it turns cubes on. That leaves every cube counted once if it's
+/// on and not at all if it's off, without ever going cube by cube.
+fn count_on(steps: &[Step]) -> u64 {
+    let mut tally: HashMap<Cuboid, i64> = HashMap::new();
+
+    for step in steps {
+        let mut changes: HashMap<Cuboid, i64> = HashMap::new();
+
+        for (cuboid, count) in &tally {
+            if let Some(overlap) = step.cuboid.intersection(cuboid) {
+                *changes.entry(overlap).or_default() -= count;
+            }
+        }
+        if step.on {
+            *changes.entry(step.cuboid).or_default() += 1;
+        }
+
+        for (cuboid, change) in changes {
+            let count = tally.entry(cuboid).or_default();
+            *count += change;
+            if *count == 0 {
+                tally.remove(&cuboid);
+            }
+        }
+    }
+
+    // the running total can stray further from zero than the answer does
+    let total: i128 = tally
+        .iter()
+        .map(|(cuboid, count)| cuboid.volume() as i128 * *count as i128)
+        .sum();
+    total as u64
+}
+
+fn part_one(steps: &[Step]) -> usize {
+    simulate(steps, INIT_REGION)
+}
+
+fn part_two(steps: &[Step]) -> u64 {
+    count_on(steps)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const INPUT: &'static str = include_str!("day22.txt");
+
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(steps: &Self::Input) -> impl fmt::Display {
+        part_one(steps)
+    }
+
+    fn part_two(steps: &Self::Input) -> impl fmt::Display {
+        part_two(steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::fixture_tests;
+    use crate::prop;
+
+    fixture_tests!(Day22);
+
+    fn shrink_step(step: &Step) -> Vec<Step> {
+        let mut smaller = Vec::new();
+
+        if !step.on {
+            smaller.push(Step { on: true, ..*step });
+        }
+        for axis in 0..3 {
+            let mut cuboid = step.cuboid;
+            let range = match axis {
+                0 => &mut cuboid.x,
+                1 => &mut cuboid.y,
+                _ => &mut cuboid.z,
+            };
+            if range.0 < range.1 {
+                range.1 -= 1;
+                smaller.push(Step { cuboid, ..*step });
+            }
+        }
+
+        smaller
+    }
+
+    #[test]
+    fn test_simulation_and_tally_agree() {
+        // the first 20 steps the generator makes stay inside the
+        // initialization region, where they can be simulated
+        prop::check(
+            prop::generated(22, 8, load_input),
+            |steps| prop::shrink_vec(steps, shrink_step),
+            |steps| prop::agree(simulate(steps, INIT_REGION) as u64, count_on(steps)),
+        );
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = Cuboid {
+            x: (10, 12),
+            y: (10, 12),
+            z: (10, 12),
+        };
+        let b = Cuboid {
+            x: (11, 13),
+            y: (11, 13),
+            z: (11, 13),
+        };
+
+        assert_eq!(
+            a.intersection(&b),
+            Some(Cuboid {
+                x: (11, 12),
+                y: (11, 12),
+                z: (11, 12),
+            })
+        );
+        assert_eq!(a.intersection(&Cuboid::around_origin(9)), None);
+    }
+
+    #[test]
+    fn test_biggest_cuboid() {
+        let steps =
+            load_input("on x=-1000000..1000000,y=-1000000..1000000,z=-1000000..1000000").unwrap();
+
+        assert_eq!(part_two(&steps), 2_000_001u64.pow(3));
+        assert_eq!(part_one(&steps), 101 * 101 * 101);
+    }
+
+    #[test]
+    fn test_bad_steps() {
+        let err = load_input("on x=1..2,y=3..4,z=5..6\nof x=1..2,y=3..4,z=5..6").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = load_input("on x=2..1,y=3..4,z=5..6").unwrap_err();
+
+        assert_eq!((err.line, err.column), (1, 6));
+        assert_eq!(err.expected, "a range from low to high");
+
+        let err = load_input("on x=1..2,y=3..4,z=5..6000000").unwrap_err();
+
+        assert_eq!((err.line, err.column), (1, 23));
+    }
+}
@@ -0,0 +1,450 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::search::{self, Path};
+use crate::{debug, trace, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Amphipod {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl Amphipod {
+    const ALL: [Amphipod; 4] = [Amphipod::A, Amphipod::B, Amphipod::C, Amphipod::D];
+
+    fn from_char(c: char) -> Option<Amphipod> {
+        match c {
+            'A' => Some(Amphipod::A),
+            'B' => Some(Amphipod::B),
+            'C' => Some(Amphipod::C),
+            'D' => Some(Amphipod::D),
+            _ => None,
+        }
+    }
+
+    /// The room it belongs in, counting from the left.
+    fn room(&self) -> usize {
+        *self as usize
+    }
+
+    fn energy(&self) -> u32 {
+        10u32.pow(self.room() as u32)
+    }
+}
+
+impl fmt::Display for Amphipod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+const HALL: usize = 11;
+
+/// The hallway spots just outside each room, where nobody can stop.
+const DOORS: [usize; 4] = [2, 4, 6, 8];
+
+/// The deepest a burrow gets, once the folded up rows are put back in.
+const MAX_DEPTH: usize = 4;
+
+/// The rows the puzzle has folded out of the middle of the diagram.
+const FOLDED: [[Amphipod; 4]; 2] = [
+    [Amphipod::D, Amphipod::C, Amphipod::B, Amphipod::A],
+    [Amphipod::D, Amphipod::B, Amphipod::A, Amphipod::C],
+];
+
+/// Where every amphipod is. Rooms are listed from the hallway down, and only
+/// their first `depth` spots are used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Burrow {
+    hall: [Option<Amphipod>; HALL],
+    rooms: [[Option<Amphipod>; MAX_DEPTH]; 4],
+    depth: usize,
+}
+
+impl Burrow {
+    fn room(&self, room: usize) -> &[Option<Amphipod>] {
+        &self.rooms[room][..self.depth]
+    }
+
+    /// Whether the room holds nobody who belongs somewhere else, so its own
+    /// amphipods can move in.
+    fn is_ready(&self, room: usize) -> bool {
+        self.room(room)
+            .iter()
+            .flatten()
+            .all(|amphipod| amphipod.room() == room)
+    }
+
+    fn is_organized(&self) -> bool {
+        (0..4).all(|room| {
+            self.room(room)
+                .iter()
+                .all(|spot| spot.is_some_and(|amphipod| amphipod.room() == room))
+        })
+    }
+
+    /// Whether the hallway is empty from `from` to `to`, leaving out `from`
+    /// itself, where the amphipod making the trip starts.
+    fn is_clear(&self, from: usize, to: usize) -> bool {
+        let between = if from < to {
+            from + 1..=to
+        } else {
+            to..=from - 1
+        };
+
+        between.into_iter().all(|spot| self.hall[spot].is_none())
+    }
+
+    /// Whether two amphipods in the hallway each stand in the other's way
+    /// home, so neither can ever get there.
+    fn is_deadlocked(&self) -> bool {
+        (0..HALL).any(|left| {
+            let Some(left_amphipod) = self.hall[left] else {
+                return false;
+            };
+
+            (left + 1..HALL).any(|right| {
+                self.hall[right].is_some_and(|right_amphipod| {
+                    DOORS[left_amphipod.room()] > right && DOORS[right_amphipod.room()] < left
+                })
+            })
+        })
+    }
+
+    /// The amphipod at the top of a room that still has to move out, and how
+    /// deep it is.
+    fn leaving(&self, room: usize) -> Option<(usize, Amphipod)> {
+        if self.is_ready(room) {
+            return None;
+        }
+
+        self.room(room)
+            .iter()
+            .enumerate()
+            .find_map(|(depth, spot)| Some((depth, (*spot)?)))
+    }
+
+    /// A move that takes an amphipod straight into its room, from the
+    /// hallway or the top of another room, along with the energy it takes.
+    /// Going home never hurts, so when there's one of these it's the only
+    /// move worth looking at.
+    fn homecoming(&self) -> Option<(Burrow, u32)> {
+        let in_hall = (0..HALL).filter_map(|spot| Some((spot, 0, self.hall[spot]?, None)));
+        let in_rooms = (0..4).filter_map(|room| {
+            let (depth, amphipod) = self.leaving(room)?;
+            Some((DOORS[room], depth + 1, amphipod, Some((room, depth))))
+        });
+
+        for (spot, steps_out, amphipod, from_room) in in_hall.chain(in_rooms) {
+            let room = amphipod.room();
+            if !self.is_ready(room) || !self.is_clear(spot, DOORS[room]) {
+                continue;
+            }
+
+            let depth = self.room(room).iter().rposition(Option::is_none).unwrap();
+            let mut next = *self;
+            match from_room {
+                Some((from, from_depth)) => next.rooms[from][from_depth] = None,
+                None => next.hall[spot] = None,
+            }
+            next.rooms[room][depth] = Some(amphipod);
+            let steps = steps_out + spot.abs_diff(DOORS[room]) + depth + 1;
+
+            return Some((next, steps as u32 * amphipod.energy()));
+        }
+
+        None
+    }
+
+    fn moves(&self) -> Vec<(Burrow, u32)> {
+        if let Some(homecoming) = self.homecoming() {
+            return vec![homecoming];
+        }
+
+        let mut moves = Vec::new();
+        for room in 0..4 {
+            let Some((depth, amphipod)) = self.leaving(room) else {
+                continue;
+            };
+
+            for spot in (0..HALL).filter(|spot| !DOORS.contains(spot)) {
+                if !self.is_clear(DOORS[room], spot) || self.hall[spot].is_some() {
+                    continue;
+                }
+
+                let mut next = *self;
+                next.rooms[room][depth] = None;
+                next.hall[spot] = Some(amphipod);
+                if next.is_deadlocked() {
+                    continue;
+                }
+                let steps = depth + 1 + spot.abs_diff(DOORS[room]);
+                moves.push((next, steps as u32 * amphipod.energy()));
+            }
+        }
+
+        moves
+    }
+
+    /// The burrow with the folded rows put back between the top and bottom
+    /// rows of each room.
+    fn unfolded(&self) -> Burrow {
+        let mut burrow = *self;
+
+        for room in 0..4 {
+            burrow.rooms[room] = [
+                self.rooms[room][0],
+                Some(FOLDED[0][room]),
+                Some(FOLDED[1][room]),
+                self.rooms[room][self.depth - 1],
+            ];
+        }
+        burrow.depth = MAX_DEPTH;
+
+        burrow
+    }
+}
+
+// The search hashes a great many burrows, and one number hashes a lot
+// quicker than every spot one at a time.
+impl Hash for Burrow {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let spots = self.hall.iter().chain(self.rooms.iter().flatten());
+        let packed = spots.fold(0u128, |packed, spot| {
+            packed << 3 | spot.map_or(0, |amphipod| amphipod as u128 + 1)
+        });
+
+        packed.hash(state);
+        self.depth.hash(state);
+    }
+}
+
+impl fmt::Display for Burrow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let spot = |spot: &Option<Amphipod>| spot.map_or(String::from("."), |a| a.to_string());
+
+        writeln!(f, "#############")?;
+        writeln!(f, "#{}#", self.hall.iter().map(spot).collect::<String>())?;
+        for depth in 0..self.depth {
+            let edge = if depth == 0 { "##" } else { "  " };
+            let row: Vec<_> = (0..4).map(|room| spot(&self.rooms[room][depth])).collect();
+            writeln!(f, "{}#{}#{}", edge, row.join("#"), edge.trim())?;
+        }
+        write!(f, "  #########")
+    }
+}
+
+/// Describes the one amphipod that moved between `from` and `to`.
+fn describe_move(from: &Burrow, to: &Burrow) -> String {
+    let place = |burrow: &Burrow| {
+        let in_hall = (0..HALL)
+            .filter(|spot| burrow.hall[*spot].is_some())
+            .map(|spot| (format!("hallway spot {}", spot), burrow.hall[spot]));
+        let in_rooms = (0..4).flat_map(move |room| {
+            (0..burrow.depth)
+                .filter(move |depth| burrow.rooms[room][*depth].is_some())
+                .map(move |depth| {
+                    (
+                        format!("room {} spot {}", Amphipod::ALL[room], depth + 1),
+                        burrow.rooms[room][depth],
+                    )
+                })
+        });
+        in_hall.chain(in_rooms).collect::<Vec<_>>()
+    };
+    let (before, after) = (place(from), place(to));
+
+    let left = before.iter().find(|spot| !after.contains(spot));
+    let arrived = after.iter().find(|spot| !before.contains(spot));
+    match (left, arrived) {
+        (Some((from, Some(amphipod))), Some((to, _))) => {
+            format!("{} moves from {} to {}", amphipod, from, to)
+        }
+        _ => String::from("nobody moves"),
+    }
+}
+
+fn log_moves(path: &Path<Burrow, u32>) {
+    debug!(
+        "organized in {} moves for {} energy",
+        path.nodes.len() - 1,
+        path.cost
+    );
+    for pair in path.nodes.windows(2) {
+        debug!("{}", describe_move(&pair[0], &pair[1]));
+        trace!("\n{}", pair[1]);
+    }
+}
+
+fn load_input(input: &str) -> Result<Burrow, ParseError> {
+    let mut lines = input.lines();
+    let mut last_line = "";
+    let mut next_line = |expected: &str| {
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::after(input, last_line, expected))?;
+        last_line = line;
+        Ok(line)
+    };
+
+    let wall = next_line("the top wall")?;
+    if wall != "#############" {
+        return Err(ParseError::at(input, wall, "`#############`"));
+    }
+    let hall = next_line("the hallway")?;
+    if hall != "#...........#" {
+        return Err(ParseError::at(
+            input,
+            hall,
+            "an empty hallway, `#...........#`",
+        ));
+    }
+
+    let mut burrow = Burrow {
+        hall: [None; HALL],
+        rooms: [[None; MAX_DEPTH]; 4],
+        depth: 2,
+    };
+    for depth in 0..burrow.depth {
+        let line = next_line("a row of rooms")?;
+        for (room, door) in DOORS.iter().enumerate() {
+            let at = door + 1;
+            let spot = line.get(at..at + 1).unwrap_or(&line[line.len()..]);
+            burrow.rooms[room][depth] = Some(
+                spot.chars()
+                    .next()
+                    .and_then(Amphipod::from_char)
+                    .ok_or_else(|| ParseError::at(input, spot, "an amphipod from A to D"))?,
+            );
+        }
+        let expected = if depth == 0 {
+            "###?#?#?#?###"
+        } else {
+            "  #?#?#?#?#"
+        };
+        let walls_match = line.len() == expected.len()
+            && line
+                .chars()
+                .zip(expected.chars())
+                .all(|(c, wall)| wall == '?' || c == wall);
+        if !walls_match {
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("a row of rooms like `{}`", expected.replace('?', "A")),
+            ));
+        }
+    }
+
+    let floor = next_line("the bottom wall")?;
+    if floor.trim_end() != "  #########" {
+        return Err(ParseError::at(input, floor, "`  #########`"));
+    }
+
+    for amphipod in Amphipod::ALL {
+        let count = (0..4)
+            .flat_map(|room| burrow.room(room))
+            .filter(|spot| **spot == Some(amphipod))
+            .count();
+        if count != burrow.depth {
+            return Err(ParseError::at(
+                input,
+                input,
+                format!("rooms holding {} of each amphipod", burrow.depth),
+            ));
+        }
+    }
+
+    Ok(burrow)
+}
+
+/// The least energy it takes to organize the amphipods, or `None` if they
+/// get stuck however they move. Every two deep burrow can be organized, but
+/// about one in eight unfolded ones can't.
+fn least_energy(burrow: &Burrow) -> Option<u32> {
+    let path = search::dijkstra(*burrow, Burrow::moves, Burrow::is_organized)?;
+
+    log_moves(&path);
+    Some(path.cost)
+}
+
+fn part_one(burrow: &Burrow) -> u32 {
+    least_energy(burrow).expect("every two deep burrow can be organized")
+}
+
+fn part_two(burrow: &Burrow) -> Option<u32> {
+    least_energy(&burrow.unfolded())
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const INPUT: &'static str = include_str!("day23.txt");
+
+    type Input = Burrow;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(burrow: &Self::Input) -> impl fmt::Display {
+        part_one(burrow)
+    }
+
+    fn part_two(burrow: &Self::Input) -> impl fmt::Display {
+        part_two(burrow).map_or_else(|| String::from("stuck"), |energy| energy.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, fixture_tests};
+
+    fixture_tests!(Day23);
+
+    #[test]
+    fn test_display() {
+        let input = fixtures::input(23, "sample");
+        let burrow = load_input(&input).unwrap();
+
+        assert_eq!(burrow.to_string() + "\n", input);
+        assert!(burrow
+            .unfolded()
+            .to_string()
+            .contains("  #D#C#B#A#\n  #D#B#A#C#\n"));
+    }
+
+    #[test]
+    fn test_moves() {
+        let burrow = load_input(&fixtures::input(23, "sample")).unwrap();
+        let moves = burrow.moves();
+
+        // the top amphipod of each room can stop at any of the 7 spots
+        assert_eq!(moves.len(), 28);
+        assert_eq!(
+            describe_move(&burrow, &moves[0].0),
+            "B moves from room A spot 1 to hallway spot 0"
+        );
+        assert_eq!(moves[0].1, 30);
+    }
+
+    #[test]
+    fn test_bad_burrow() {
+        let err =
+            load_input("#############\n#...........#\n###B#C#B#D###\n  #A#D#C#E#\n  #########")
+                .unwrap_err();
+
+        assert_eq!((err.line, err.column), (4, 10));
+
+        let err =
+            load_input("#############\n#...........#\n###B#C#B#D###\n  #A#D#C#B#\n  #########")
+                .unwrap_err();
+
+        assert_eq!(err.expected, "rooms holding 2 of each amphipod");
+    }
+}
@@ -0,0 +1,461 @@
+use std::fmt;
+
+use crate::repl::Explorer;
+use crate::{debug, parse, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl Register {
+    fn load(input: &str, text: &str) -> Result<Register, ParseError> {
+        match text {
+            "w" => Ok(Register::W),
+            "x" => Ok(Register::X),
+            "y" => Ok(Register::Y),
+            "z" => Ok(Register::Z),
+            _ => Err(ParseError::at(input, text, "a register from w to z")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Register(Register),
+    Number(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Inp(Register),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    Eql(Register, Operand),
+}
+
+fn load_instruction(input: &str, line: &str) -> Result<Instruction, ParseError> {
+    let mut split = line.split(' ');
+    let op = parse::next(input, line, &mut split, "an instruction")?;
+    let a = Register::load(input, parse::next(input, line, &mut split, "a register")?)?;
+
+    if op == "inp" {
+        if let Some(extra) = split.next() {
+            return Err(ParseError::at(input, extra, "the end of the line"));
+        }
+        return Ok(Instruction::Inp(a));
+    }
+
+    let b = parse::next(input, line, &mut split, "a register or a number")?;
+    let b = match b {
+        "w" | "x" | "y" | "z" => Operand::Register(Register::load(input, b)?),
+        _ => Operand::Number(parse::field(input, b, "a register or a number")?),
+    };
+    if let Some(extra) = split.next() {
+        return Err(ParseError::at(input, extra, "the end of the line"));
+    }
+
+    match op {
+        "add" => Ok(Instruction::Add(a, b)),
+        "mul" => Ok(Instruction::Mul(a, b)),
+        "div" => Ok(Instruction::Div(a, b)),
+        "mod" => Ok(Instruction::Mod(a, b)),
+        "eql" => Ok(Instruction::Eql(a, b)),
+        _ => Err(ParseError::at(
+            input,
+            op,
+            "one of `inp`, `add`, `mul`, `div`, `mod` or `eql`",
+        )),
+    }
+}
+
+fn load_program(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| load_instruction(input, line))
+        .collect()
+}
+
+/// Runs `program` with `inputs` for its `inp` instructions, returning the
+/// registers once it's done, or what stopped it.
+fn run(program: &[Instruction], inputs: &[i64]) -> Result<[i64; 4], String> {
+    let mut registers = [0i64; 4];
+    let mut inputs = inputs.iter();
+
+    for (index, instruction) in program.iter().enumerate() {
+        let fail = |what: &str| format!("{} at instruction {}", what, index + 1);
+        let value = |operand| match operand {
+            Operand::Register(register) => registers[register as usize],
+            Operand::Number(number) => number,
+        };
+
+        let (register, result) = match *instruction {
+            Instruction::Inp(a) => (
+                a,
+                Some(*inputs.next().ok_or_else(|| fail("ran out of input"))?),
+            ),
+            Instruction::Add(a, b) => (a, registers[a as usize].checked_add(value(b))),
+            Instruction::Mul(a, b) => (a, registers[a as usize].checked_mul(value(b))),
+            Instruction::Div(_, b) if value(b) == 0 => return Err(fail("divided by zero")),
+            Instruction::Div(a, b) => (a, registers[a as usize].checked_div(value(b))),
+            Instruction::Mod(a, b) if registers[a as usize] < 0 || value(b) <= 0 => {
+                return Err(fail(
+                    "took a remainder of a negative number, or by one below 1",
+                ));
+            }
+            Instruction::Mod(a, b) => (a, Some(registers[a as usize] % value(b))),
+            Instruction::Eql(a, b) => (a, Some((registers[a as usize] == value(b)) as i64)),
+        };
+        registers[register as usize] = result.ok_or_else(|| fail("overflowed"))?;
+    }
+
+    Ok(registers)
+}
+
+/// Every block of MONAD is this, apart from the numbers in braces.
+pub(crate) const TEMPLATE: [&str; 18] = [
+    "inp w",
+    "mul x 0",
+    "add x z",
+    "mod x 26",
+    "div z {1 or 26}",
+    "add x {check}",
+    "eql x w",
+    "eql x 0",
+    "mul y 0",
+    "add y 25",
+    "mul y x",
+    "add y 1",
+    "mul z y",
+    "mul y 0",
+    "add y w",
+    "add y {offset}",
+    "mul y x",
+    "add z y",
+];
+
+/// How many digits a model number has, and so how many blocks MONAD has.
+const DIGITS: usize = 14;
+
+/// One digit's worth of MONAD. `z` works as a stack of base 26 digits: a
+/// block that doesn't pop one pushes its digit plus `offset`, and a block
+/// that does pop one only leaves it at that if its digit is the popped
+/// value plus `check`, and pushes one of its own instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block {
+    pub pops: bool,
+    pub check: i64,
+    pub offset: i64,
+}
+
+/// A MONAD program, along with what each of its blocks does.
+#[derive(Debug, Clone)]
+pub struct Monad {
+    pub program: Vec<Instruction>,
+    pub blocks: Vec<Block>,
+}
+
+/// The number in braces in `TEMPLATE`, checking the rest of `line` matches.
+fn load_constant(input: &str, line: &str, template: &str) -> Result<i64, ParseError> {
+    let (prefix, _) = template.split_once('{').unwrap();
+    let number = parse::prefix(input, line, prefix)?;
+
+    parse::field(input, number, "a number")
+}
+
+/// The numbers in one block, whose other lines have already been checked.
+fn load_block(input: &str, lines: &[&str]) -> Result<Block, ParseError> {
+    let pops = match load_constant(input, lines[4], TEMPLATE[4])? {
+        1 => false,
+        26 => true,
+        _ => return Err(ParseError::at(input, lines[4], "`div z 1` or `div z 26`")),
+    };
+    let check = load_constant(input, lines[5], TEMPLATE[5])?;
+    let offset = load_constant(input, lines[15], TEMPLATE[15])?;
+
+    // the analysis leans on pushing blocks never matching their digit, and
+    // on every pushed value being a single base 26 digit
+    if !pops && check < 10 {
+        return Err(ParseError::at(input, lines[5], "a check of 10 or more"));
+    }
+    if !(0..=16).contains(&offset) {
+        return Err(ParseError::at(input, lines[15], "an offset from 0 to 16"));
+    }
+
+    Ok(Block {
+        pops,
+        check,
+        offset,
+    })
+}
+
+fn load_input(input: &str) -> Result<Monad, ParseError> {
+    let program = load_program(input)?;
+    let lines: Vec<_> = input.lines().filter(|line| !line.is_empty()).collect();
+    for (line, template) in lines.iter().zip(TEMPLATE.iter().cycle()) {
+        if !template.contains('{') && line != template {
+            return Err(ParseError::at(input, line, format!("`{}`", template)));
+        }
+    }
+    if lines.len() != DIGITS * TEMPLATE.len() {
+        return Err(ParseError::after(
+            input,
+            lines.last().copied().unwrap_or(input),
+            format!("{} blocks of {} instructions", DIGITS, TEMPLATE.len()),
+        ));
+    }
+
+    let blocks = lines
+        .chunks(TEMPLATE.len())
+        .map(|block| load_block(input, block))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut pushed = 0;
+    for (block, lines) in blocks.iter().zip(lines.chunks(TEMPLATE.len())) {
+        if !block.pops {
+            pushed += 1;
+        } else if pushed == 0 {
+            return Err(ParseError::at(
+                input,
+                lines[4],
+                "`div z 1`, as nothing's been pushed",
+            ));
+        } else {
+            pushed -= 1;
+        }
+    }
+    if pushed > 0 {
+        return Err(ParseError::after(
+            input,
+            lines[lines.len() - 1],
+            "as many blocks popping as pushing",
+        ));
+    }
+
+    Ok(Monad { program, blocks })
+}
+
+/// The pairs of blocks where the first pushes a value the second pops.
+fn pairs(blocks: &[Block]) -> Vec<(usize, usize)> {
+    let mut stack = Vec::new();
+    let mut pairs = Vec::new();
+
+    for (index, block) in blocks.iter().enumerate() {
+        if block.pops {
+            pairs.push((stack.pop().unwrap(), index));
+        } else {
+            stack.push(index);
+        }
+    }
+
+    pairs
+}
+
+/// The model number MONAD accepts with the highest digits it can, or the
+/// lowest when `highest` is false, or `None` if it accepts none at all.
+///
+/// A pair of blocks only pops what was pushed when the second digit is the
+/// first plus the pushing block's offset plus the popping block's check, so
+/// each pair's digits are picked together, as far up or down as that allows.
+fn model_number(blocks: &[Block], highest: bool) -> Option<[i64; DIGITS]> {
+    let mut digits = [0; DIGITS];
+
+    for (push, pop) in pairs(blocks) {
+        let gap = blocks[push].offset + blocks[pop].check;
+        if gap.abs() > 8 {
+            return None;
+        }
+
+        let first = if highest {
+            9 - gap.max(0)
+        } else {
+            1 - gap.min(0)
+        };
+        digits[push] = first;
+        digits[pop] = first + gap;
+        debug!("digit {} is digit {} {:+}", pop + 1, push + 1, gap);
+    }
+
+    Some(digits)
+}
+
+fn show_number(digits: Option<[i64; DIGITS]>) -> String {
+    match digits {
+        Some(digits) => digits.iter().map(i64::to_string).collect(),
+        None => String::from("none"),
+    }
+}
+
+fn part_one(monad: &Monad) -> String {
+    show_number(model_number(&monad.blocks, true))
+}
+
+fn part_two(monad: &Monad) -> String {
+    show_number(model_number(&monad.blocks, false))
+}
+
+/// MONAD, for trying model numbers on.
+struct Console {
+    monad: Monad,
+}
+
+impl Explorer for Console {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("run", "run MONAD on a model number and show the registers"),
+            ("blocks", "list what each block pushes or checks"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match (command, args) {
+            ("run", [number]) => {
+                let digits = number
+                    .chars()
+                    .map(|c| c.to_digit(10).filter(|digit| *digit > 0))
+                    .collect::<Option<Vec<_>>>()
+                    .filter(|digits| digits.len() == DIGITS)
+                    .ok_or(format!(
+                        "`{}` is not a model number, expected {} digits from 1 to 9",
+                        number, DIGITS
+                    ))?;
+                let inputs: Vec<_> = digits.into_iter().map(i64::from).collect();
+
+                let [w, x, y, z] = run(&self.monad.program, &inputs)?;
+                let verdict = if z == 0 { "valid" } else { "invalid" };
+                Ok(format!("w={} x={} y={} z={}: {}", w, x, y, z, verdict))
+            }
+            ("run", _) => Err(String::from("expected one model number to run")),
+            _ => Ok(self
+                .monad
+                .blocks
+                .iter()
+                .enumerate()
+                .map(|(index, block)| {
+                    if block.pops {
+                        format!("digit {:2}: pops, checks {:+}", index + 1, block.check)
+                    } else {
+                        format!("digit {:2}: pushes it {:+}", index + 1, block.offset)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n")),
+        }
+    }
+}
+
+pub(crate) fn explore(input: &str) -> Result<Box<dyn Explorer>, ParseError> {
+    Ok(Box::new(Console {
+        monad: load_input(input)?,
+    }))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const INPUT: &'static str = include_str!("day24.txt");
+
+    type Input = Monad;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(monad: &Self::Input) -> impl fmt::Display {
+        part_one(monad)
+    }
+
+    fn part_two(monad: &Self::Input) -> impl fmt::Display {
+        part_two(monad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::fixture_tests;
+
+    fixture_tests!(Day24);
+
+    #[test]
+    fn test_run() {
+        let negate = load_program("inp x\nmul x -1").unwrap();
+
+        assert_eq!(run(&negate, &[7]), Ok([0, -7, 0, 0]));
+
+        let binary = load_program(
+            "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\nadd x w\nmod x 2\n\
+             div w 2\nmod w 2",
+        )
+        .unwrap();
+
+        assert_eq!(run(&binary, &[13]), Ok([1, 1, 0, 1]));
+        assert_eq!(
+            run(&binary, &[]),
+            Err(String::from("ran out of input at instruction 1"))
+        );
+
+        let divide = load_program("inp w\ndiv w x").unwrap();
+
+        assert_eq!(
+            run(&divide, &[1]),
+            Err(String::from("divided by zero at instruction 2"))
+        );
+    }
+
+    #[test]
+    fn test_model_numbers_are_valid() {
+        let monad = load_input(Day24::INPUT).unwrap();
+
+        for highest in [true, false] {
+            let digits = model_number(&monad.blocks, highest).unwrap();
+            let [.., z] = run(&monad.program, &digits).unwrap();
+
+            assert_eq!(z, 0);
+        }
+    }
+
+    #[test]
+    fn test_console() {
+        let mut console = explore(Day24::INPUT).unwrap();
+        let highest = part_one(&load_input(Day24::INPUT).unwrap());
+
+        assert!(console
+            .run("run", &[&highest])
+            .unwrap()
+            .ends_with("z=0: valid"));
+        assert!(console.run("run", &["12345"]).is_err());
+        assert!(console
+            .run("blocks", &[])
+            .unwrap()
+            .starts_with("digit  1: pushes"));
+    }
+
+    #[test]
+    fn test_bad_monad() {
+        let err = load_input("inp w\nmul x 0").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 8));
+        assert_eq!(err.expected, "14 blocks of 18 instructions");
+
+        let err = load_input("inp w\nmul x 0\nadd x z\nmod x w").unwrap_err();
+
+        assert_eq!((err.line, err.column), (4, 1));
+        assert_eq!(err.expected, "`mod x 26`");
+
+        let err = load_input(&Day24::INPUT.replacen("div z 1\n", "div z 26\n", 1)).unwrap_err();
+
+        assert_eq!((err.line, err.column), (5, 1));
+
+        let err = load_input("inp v").unwrap_err();
+
+        assert_eq!(err.expected, "a register from w to z");
+    }
+}
@@ -0,0 +1,186 @@
+use std::fmt;
+use std::iter;
+
+use colored::Color;
+
+use crate::animate::{Cell, Frame, Frames};
+use crate::grid::{Grid, Pos};
+use crate::search;
+use crate::{debug, trace, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spot {
+    Empty,
+    East,
+    South,
+}
+
+impl fmt::Display for Spot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Spot::Empty => '.',
+            Spot::East => '>',
+            Spot::South => 'v',
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+fn load_input(input: &str) -> Result<Grid<Spot>, ParseError> {
+    Grid::parse(input, input, "`>`, `v` or `.`", |c| match c {
+        '.' => Some(Spot::Empty),
+        '>' => Some(Spot::East),
+        'v' => Some(Spot::South),
+        _ => None,
+    })
+}
+
+/// Moves every sea cucumber in `herd` that has room to, all at once, going
+/// round to the other side off the edges. Returns how many moved.
+fn move_herd(spots: &mut Grid<Spot>, herd: Spot) -> usize {
+    let (width, height) = (spots.width(), spots.height());
+    let ahead = |pos: Pos| match herd {
+        Spot::East => Pos::new((pos.x + 1) % width, pos.y),
+        _ => Pos::new(pos.x, (pos.y + 1) % height),
+    };
+
+    let moving: Vec<_> = spots
+        .iter()
+        .filter(|(pos, spot)| **spot == herd && spots[ahead(*pos)] == Spot::Empty)
+        .map(|(pos, _)| pos)
+        .collect();
+
+    for &pos in &moving {
+        spots[pos] = Spot::Empty;
+        spots[ahead(pos)] = herd;
+    }
+
+    moving.len()
+}
+
+/// One step: the east-facing herd moves, then the south-facing one. Returns
+/// how many sea cucumbers moved.
+fn perform_step(spots: &mut Grid<Spot>) -> usize {
+    move_herd(spots, Spot::East) + move_herd(spots, Spot::South)
+}
+
+/// The first step on which no sea cucumber moves, or `None` if that never
+/// happens.
+fn part_one(spots: &Grid<Spot>) -> Option<usize> {
+    let mut step = 0;
+
+    search::steps_until(spots.clone(), |spots| {
+        step += 1;
+        let moved = perform_step(spots);
+        debug!("step {}: {} moved", step, moved);
+        trace!("\n{}", spots);
+
+        moved == 0
+    })
+}
+
+/// There's no puzzle for part two: the last star comes with the other 49.
+fn part_two(_spots: &Grid<Spot>) -> &'static str {
+    "Merry Christmas!"
+}
+
+/// The sea cucumbers after every step, until they stop.
+pub(crate) fn frames(input: &str) -> Result<Frames, ParseError> {
+    let spots = load_input(input)?;
+
+    let frames = iter::successors(Some((spots, 0, None)), |(spots, step, moved)| {
+        if *moved == Some(0) {
+            return None;
+        }
+        let mut spots = spots.clone();
+        let moved = perform_step(&mut spots);
+        Some((spots, step + 1, Some(moved)))
+    })
+    .map(|(spots, step, moved)| Frame {
+        caption: format!("step {}: {} moved", step, moved.unwrap_or(0)),
+        cells: spots.map(|spot| match spot {
+            Spot::Empty => Cell::plain('.'),
+            Spot::East => Cell::colored('>', Color::BrightGreen),
+            Spot::South => Cell::colored('v', Color::Cyan),
+        }),
+    });
+
+    Ok(Box::new(frames))
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const INPUT: &'static str = include_str!("day25.txt");
+
+    type Input = Grid<Spot>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(spots: &Self::Input) -> impl fmt::Display {
+        part_one(spots).map_or_else(|| String::from("never stops"), |step| step.to_string())
+    }
+
+    fn part_two(spots: &Self::Input) -> impl fmt::Display {
+        part_two(spots)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, fixture_tests};
+
+    fixture_tests!(Day25);
+
+    #[test]
+    fn test_steps() {
+        let mut spots = load_input("...>>>>>...").unwrap();
+
+        assert_eq!(perform_step(&mut spots), 1);
+        assert_eq!(spots.to_string(), "...>>>>.>..");
+        assert_eq!(perform_step(&mut spots), 2);
+        assert_eq!(spots.to_string(), "...>>>.>.>.");
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let input = "
+..........
+.>v....v..
+.......>..
+..........
+";
+        let mut spots = load_input(input).unwrap();
+        perform_step(&mut spots);
+
+        assert_eq!(
+            spots.to_string(),
+            "..........\n.>........\n..v....v>.\n.........."
+        );
+
+        let mut spots = load_input(">.v\n...\nv.>").unwrap();
+        perform_step(&mut spots);
+
+        assert_eq!(spots.to_string(), "v>.\n..v\n..>");
+    }
+
+    #[test]
+    fn test_herds_that_never_stop() {
+        assert_eq!(part_one(&load_input(">.\n..").unwrap()), None);
+        assert_eq!(part_one(&load_input(">.v\n...\n...").unwrap()), None);
+        assert_eq!(part_one(&load_input(">>\n..").unwrap()), Some(1));
+    }
+
+    #[test]
+    fn test_frames() {
+        let sample = fixtures::input(25, "sample");
+        let frames: Vec<_> = frames(&sample).unwrap().collect();
+
+        assert_eq!(frames.len(), 59);
+        assert_eq!(frames[58].caption, "step 58: 0 moved");
+    }
+}
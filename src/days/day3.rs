@@ -0,0 +1,185 @@
+use std::fmt;
+
+use crate::{ParseError, Solution};
+
+fn part_one(report: &[u16]) -> u32 {
+    let entries = report.len() as u32;
+    let mut ones_count = [0u32; 12];
+
+    for value in report {
+        for i in (0..12).rev() {
+            let mask = 0x1 << i;
+            if value & mask != 0 {
+                ones_count[i] += 1
+            }
+        }
+    }
+
+    let mut gamma = 0u16;
+    for i in 0..ones_count.len() {
+        let count = ones_count[i];
+        if count > (entries / 2) {
+            let mask = 0x1 << i;
+            gamma |= mask;
+        }
+    }
+
+    let epsilon = gamma ^ 0xF_FF;
+
+    (gamma as u32) * (epsilon as u32)
+}
+
+#[derive(PartialEq)]
+enum PickPartition {
+    Greater,
+    Lesser,
+}
+
+fn partition_until(report: &[u16], pick_partition: PickPartition) -> u32 {
+    let mut curr_report = report.to_vec();
+
+    for curr_position in (0..12).rev() {
+        if curr_report.len() <= 1 {
+            break;
+        }
+
+        let mut ones = Vec::new();
+        let mut zeroes = Vec::new();
+
+        for value in &curr_report {
+            let mask = 0x1 << curr_position;
+            if value & mask != 0 {
+                ones.push(*value);
+            } else {
+                zeroes.push(*value);
+            }
+        }
+
+        let greater;
+        let lesser;
+
+        if ones.len() >= zeroes.len() {
+            greater = ones;
+            lesser = zeroes;
+        } else {
+            greater = zeroes;
+            lesser = ones;
+        }
+
+        // when every number left has the same bit there's nothing to drop
+        curr_report = if pick_partition == PickPartition::Greater || lesser.is_empty() {
+            greater
+        } else {
+            lesser
+        };
+    }
+
+    // anything left after the last bit is the same number repeated
+    curr_report[0] as u32
+}
+
+fn part_two(report: &[u16]) -> u32 {
+    let oxygen = partition_until(report, PickPartition::Greater);
+    let co2 = partition_until(report, PickPartition::Lesser);
+
+    oxygen * co2
+}
+
+fn load_input(input: &str) -> Result<Vec<u16>, ParseError> {
+    let report = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            if let Some(i) = line.find(|c| c != '0' && c != '1') {
+                return Err(ParseError::at(input, &line[i..], "a binary digit"));
+            }
+            if line.len() != 12 {
+                return Err(ParseError::at(input, line, "12 binary digits"));
+            }
+            Ok(u16::from_str_radix(line, 2).unwrap())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if report.is_empty() {
+        return Err(ParseError::at(
+            input,
+            input,
+            "at least one number in the report",
+        ));
+    }
+
+    Ok(report)
+}
+
+/// Every digit that isn't binary and every number that isn't 12 digits
+/// long, rather than just the first, and an empty report.
+pub(crate) fn validate(input: &str) -> Vec<ParseError> {
+    let mut problems = vec![];
+    let mut numbers = 0;
+
+    for line in input.lines().filter(|line| !line.is_empty()) {
+        for (i, c) in line.char_indices() {
+            if c != '0' && c != '1' {
+                problems.push(ParseError::at(input, &line[i..], "a binary digit"));
+            }
+        }
+        if line.len() != 12 {
+            problems.push(ParseError::at(input, line, "12 binary digits"));
+        }
+        numbers += 1;
+    }
+    if numbers == 0 {
+        problems.push(ParseError::at(
+            input,
+            input,
+            "at least one number in the report",
+        ));
+    }
+
+    problems
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const INPUT: &'static str = include_str!("day3.txt");
+
+    type Input = Vec<u16>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(report: &Self::Input) -> impl fmt::Display {
+        part_one(report)
+    }
+
+    fn part_two(report: &Self::Input) -> impl fmt::Display {
+        part_two(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::fixture_tests;
+
+    fixture_tests!(Day3);
+
+    #[test]
+    fn test_awkward_reports() {
+        let err = load_input("\n").unwrap_err();
+
+        assert_eq!(err.expected, "at least one number in the report");
+
+        let report = load_input("000000000000\n000000000001\n000000000001").unwrap();
+
+        assert_eq!(partition_until(&report, PickPartition::Greater), 1);
+        assert_eq!(partition_until(&report, PickPartition::Lesser), 0);
+
+        let report = load_input("000000000001\n000000000011").unwrap();
+
+        assert_eq!(partition_until(&report, PickPartition::Lesser), 1);
+    }
+}
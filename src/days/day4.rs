@@ -0,0 +1,595 @@
+use std::fmt;
+
+use crate::repl::{self, Explorer};
+use crate::{debug, parse, trace, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy)]
+struct BoardCell {
+    number: u32,
+    marked: bool,
+}
+
+impl BoardCell {
+    fn new(number: u32) -> BoardCell {
+        BoardCell {
+            number,
+            marked: false,
+        }
+    }
+}
+
+impl fmt::Display for BoardCell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.marked {
+            write!(f, "{:2} [X]", self.number)
+        } else {
+            write!(f, "{:2} [ ]", self.number)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Board {
+    cells: [BoardCell; 25],
+    score: Option<u32>,
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+			"{}\t{}\t{}\t{}\t{}\n\n{}\t{}\t{}\t{}\t{}\n\n{}\t{}\t{}\t{}\t{}\n\n{}\t{}\t{}\t{}\t{}\n\n{}\t{}\t{}\t{}\t{}\n\n",
+			self.cells[0], self.cells[1], self.cells[2], self.cells[3], self.cells[4],
+			self.cells[5], self.cells[6], self.cells[7], self.cells[8], self.cells[9],
+			self.cells[10], self.cells[11], self.cells[12], self.cells[13], self.cells[14],
+			self.cells[15], self.cells[16], self.cells[17], self.cells[18], self.cells[19],
+			self.cells[20], self.cells[21], self.cells[22], self.cells[23], self.cells[24],
+		)
+    }
+}
+
+impl Board {
+    fn new() -> Board {
+        Board {
+            cells: [BoardCell::new(0); 25],
+            score: None,
+        }
+    }
+
+    fn new_from(nums: &[u32]) -> Board {
+        let mut board = Board::new();
+        for (i, num) in nums.iter().enumerate() {
+            board.cells[i].number = *num;
+        }
+        board
+    }
+
+    fn mark_number(&mut self, number: u32) -> Option<u32> {
+        for cell_index in 0..self.cells.len() {
+            let cell = &mut self.cells[cell_index];
+            if cell.number == number && !cell.marked {
+                cell.marked = true;
+                if self.is_completed_at(cell_index) {
+                    let unmarked_cells_sum: u32 = self
+                        .cells
+                        .iter()
+                        .filter(|cell| !cell.marked)
+                        .map(|cell| cell.number)
+                        .sum();
+                    self.score = Some(number * unmarked_cells_sum);
+                }
+            }
+        }
+        self.score
+    }
+
+    fn is_completed_at(&self, index: usize) -> bool {
+        let mut winnable_rows = Vec::new();
+
+        let col_offset = index % 5;
+
+        let hor_row = ((index - col_offset)..(index - col_offset + 5)).collect::<Vec<_>>();
+        winnable_rows.push(hor_row);
+
+        let ver_row = (0..5)
+            .map(|i| ((i * 5) + col_offset) % 25)
+            .collect::<Vec<_>>();
+        winnable_rows.push(ver_row);
+
+        let diag_downward_row = (0..5)
+            .enumerate()
+            .map(|(col, row)| (row * 5) + col)
+            .collect::<Vec<_>>();
+
+        let diag_upward_row = (0..5)
+            .rev()
+            .enumerate()
+            .map(|(col, row)| (row * 5) + col)
+            .collect::<Vec<_>>();
+
+        if diag_downward_row.contains(&index) {
+            winnable_rows.push(diag_downward_row);
+        }
+        if diag_upward_row.contains(&index) {
+            winnable_rows.push(diag_upward_row);
+        }
+
+        winnable_rows.into_iter().any(|row| {
+            if row.iter().all(|cell_index| self.cells[*cell_index].marked) {
+                trace!("won for row: {:?}", row);
+                return true;
+            }
+            false
+        })
+    }
+}
+
+fn load_input(input: &str) -> Result<(Vec<Board>, Vec<u32>), ParseError> {
+    let mut lines = input.lines();
+
+    let numbers = lines
+        .next()
+        .ok_or_else(|| ParseError::at(input, input, "the drawn numbers"))?
+        .split(',')
+        .map(|str_num| parse::field(input, str_num, "a drawn number"))
+        .collect::<Result<Vec<u32>, _>>()?;
+
+    let mut boards = Vec::new();
+    let mut curr_nums = Vec::new();
+    let mut last_line = "";
+
+    if let Some(line) = lines.next() {
+        if !line.is_empty() {
+            return Err(ParseError::at(input, line, "a blank line"));
+        }
+    }
+
+    for line in lines {
+        if line.is_empty() {
+            if !curr_nums.is_empty() {
+                boards.push(finish_board(input, last_line, &curr_nums)?);
+                curr_nums.clear();
+            }
+        } else {
+            let row = line
+                .split_whitespace()
+                .map(|board_num| parse::field(input, board_num, "a board number"))
+                .collect::<Result<Vec<u32>, _>>()?;
+            if row.len() != 5 {
+                return Err(ParseError::at(input, line, "a row of 5 numbers"));
+            }
+            if curr_nums.len() == 25 {
+                return Err(ParseError::at(input, line, "a blank line after 5 rows"));
+            }
+            curr_nums.extend(row);
+        }
+        last_line = line;
+    }
+
+    if !curr_nums.is_empty() {
+        boards.push(finish_board(input, last_line, &curr_nums)?);
+    }
+    if boards.is_empty() {
+        return Err(ParseError::after(
+            input,
+            input.trim_end(),
+            "at least one board",
+        ));
+    }
+
+    Ok((boards, numbers))
+}
+
+fn finish_board(input: &str, last_line: &str, nums: &[u32]) -> Result<Board, ParseError> {
+    if nums.len() != 25 {
+        return Err(ParseError::after(input, last_line, "5 rows"));
+    }
+
+    Ok(Board::new_from(nums))
+}
+
+/// Every number that isn't one, row that isn't 5 numbers long and board that
+/// isn't 5 rows tall, and a missing first board, rather than just the first.
+pub(crate) fn validate(input: &str) -> Vec<ParseError> {
+    let mut problems = vec![];
+    let mut lines = input.lines();
+
+    for number in lines.next().unwrap_or("").split(',') {
+        if number.parse::<u32>().is_err() {
+            problems.push(ParseError::at(input, number, "a drawn number"));
+        }
+    }
+
+    let mut rows = 0;
+    let mut boards = 0;
+    let mut last_line = "";
+    for line in lines {
+        if line.trim().is_empty() {
+            if rows != 0 && rows != 5 {
+                problems.push(ParseError::after(input, last_line, "5 rows"));
+            }
+            rows = 0;
+            continue;
+        }
+        if rows == 0 {
+            boards += 1;
+        }
+
+        let numbers: Vec<_> = line.split_whitespace().collect();
+        for number in &numbers {
+            if number.parse::<u32>().is_err() {
+                problems.push(ParseError::at(input, number, "a board number"));
+            }
+        }
+        if numbers.len() != 5 {
+            problems.push(ParseError::at(input, line, "a row of 5 numbers"));
+        }
+        rows += 1;
+        last_line = line;
+    }
+    if rows != 0 && rows != 5 {
+        problems.push(ParseError::after(input, last_line, "5 rows"));
+    }
+    if boards == 0 {
+        problems.push(ParseError::after(
+            input,
+            input.trim_end(),
+            "at least one board",
+        ));
+    }
+
+    problems
+}
+
+/// The score of the first board to win, or `None` if none of them do.
+fn part_one(mut boards: Vec<Board>, numbers: &[u32]) -> Option<u32> {
+    for num in numbers {
+        for board in &mut boards {
+            if let Some(score) = board.mark_number(*num) {
+                return Some(score);
+            }
+        }
+    }
+
+    None
+}
+
+#[allow(dead_code)]
+fn part_two(mut boards: Vec<Board>, numbers: &[u32]) -> Option<u32> {
+    let mut remaining = boards.len();
+
+    for number in numbers {
+        debug!("marking number {}, {} boards remaining", number, remaining);
+        for board in &mut boards {
+            if board.score.is_some() {
+                continue;
+            }
+            let did_win = board.mark_number(*number);
+            if let Some(score) = did_win {
+                if remaining == 1 {
+                    debug!("won with board:\n{}", board);
+                    return Some(score);
+                } else {
+                    debug!("completed board:\n{}", board);
+                    remaining -= 1;
+                }
+            } else {
+                trace!("unsolved board:\n{}", board);
+            }
+        }
+    }
+
+    None
+}
+
+/// The score of the last board to win, or `None` if none of them do.
+fn part_two_other(mut boards: Vec<Board>, numbers: &[u32]) -> Option<u32> {
+    boards
+        .iter_mut()
+        .filter_map(|board| {
+            for (index, number) in numbers.iter().enumerate() {
+                if let Some(score) = board.mark_number(*number) {
+                    return Some((score, index + 1));
+                }
+            }
+            None
+        })
+        .fold(None, |last, (score, turns)| match last {
+            Some((_, most_turns)) if most_turns >= turns => last,
+            _ => Some((score, turns)),
+        })
+        .map(|(score, _)| score)
+}
+
+/// A game of bingo played a number at a time.
+struct Bingo {
+    boards: Vec<Board>,
+    numbers: Vec<u32>,
+    drawn: usize,
+}
+
+impl Bingo {
+    fn draw(&mut self, number: u32) -> String {
+        self.drawn += 1;
+
+        let mut reply = format!("drew {}", number);
+        for (index, board) in self.boards.iter_mut().enumerate() {
+            if board.score.is_some() {
+                continue;
+            }
+            if let Some(score) = board.mark_number(number) {
+                reply += &format!("\nboard {} won with a score of {}", index, score);
+            }
+        }
+
+        reply
+    }
+
+    fn show(&self, args: &[&str]) -> Result<String, String> {
+        match args {
+            [] => {
+                let won = self
+                    .boards
+                    .iter()
+                    .filter(|board| board.score.is_some())
+                    .count();
+                Ok(format!(
+                    "drew {} of {} numbers: {:?}\n{} of {} boards won",
+                    self.drawn,
+                    self.numbers.len(),
+                    &self.numbers[..self.drawn],
+                    won,
+                    self.boards.len()
+                ))
+            }
+            [arg] => {
+                let board = arg
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| self.boards.get(index))
+                    .ok_or(format!(
+                        "`{}` is not a board, expected 0 to {}",
+                        arg,
+                        self.boards.len() - 1
+                    ))?;
+                Ok(board.to_string().trim_end().to_string())
+            }
+            [_, extra, ..] => Err(format!("unexpected argument `{}`", extra)),
+        }
+    }
+}
+
+impl Explorer for Bingo {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("step", "draw the next number, or the next <n>"),
+            ("show", "list the numbers drawn, or show board <n>"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "step" => {
+                let count = repl::count(args)?;
+                let left = &self.numbers[self.drawn..];
+                if left.is_empty() {
+                    return Err(String::from("every number has been drawn"));
+                }
+
+                let numbers = left[..count.min(left.len())].to_vec();
+                let draws: Vec<_> = numbers.into_iter().map(|n| self.draw(n)).collect();
+                Ok(draws.join("\n"))
+            }
+            _ => self.show(args),
+        }
+    }
+}
+
+pub(crate) fn explore(input: &str) -> Result<Box<dyn Explorer>, ParseError> {
+    let (boards, numbers) = load_input(input)?;
+
+    Ok(Box::new(Bingo {
+        boards,
+        numbers,
+        drawn: 0,
+    }))
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const INPUT: &'static str = include_str!("day4.txt");
+
+    type Input = (Vec<Board>, Vec<u32>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one((boards, numbers): &Self::Input) -> impl fmt::Display {
+        part_one(boards.clone(), numbers)
+            .map_or_else(|| String::from("none"), |score| score.to_string())
+    }
+
+    fn part_two((boards, numbers): &Self::Input) -> impl fmt::Display {
+        part_two_other(boards.clone(), numbers)
+            .map_or_else(|| String::from("none"), |score| score.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, fixture_tests};
+    use crate::prop;
+
+    fixture_tests!(Day4);
+
+    type Game = (Vec<[u32; 25]>, Vec<u32>);
+
+    fn shrink_game((boards, numbers): &Game) -> Vec<Game> {
+        let shrink_board = |board: &[u32; 25]| {
+            let mut smaller = Vec::new();
+            for i in 0..25 {
+                for number in prop::shrink_number(board[i]) {
+                    let mut board = *board;
+                    board[i] = number;
+                    smaller.push(board);
+                }
+            }
+            smaller
+        };
+
+        let fewer_boards = prop::shrink_vec(boards, shrink_board)
+            .into_iter()
+            .map(|boards| (boards, numbers.clone()));
+        let fewer_numbers = prop::shrink_vec(numbers, |number| prop::shrink_number(*number))
+            .into_iter()
+            .map(|numbers| (boards.clone(), numbers));
+
+        fewer_boards.chain(fewer_numbers).collect()
+    }
+
+    /// Whether every board wins and just one of them wins last, as the puzzle
+    /// promises. Which board counts as last is anyone's guess otherwise.
+    fn has_one_last_winner(boards: &[Board], numbers: &[u32]) -> bool {
+        let turns: Option<Vec<_>> = boards
+            .iter()
+            .map(|board| {
+                let mut board = *board;
+                numbers
+                    .iter()
+                    .position(|number| board.mark_number(*number).is_some())
+            })
+            .collect();
+        let Some(turns) = turns else {
+            return false;
+        };
+        let last = turns.iter().max();
+
+        turns.iter().filter(|turns| Some(*turns) == last).count() == 1
+    }
+
+    #[test]
+    fn test_part_two_implementations_agree() {
+        let game = prop::generated(4, 5, load_input);
+        let numbers_on = |board: &Board| board.cells.map(|cell| cell.number);
+
+        prop::check(
+            |rng| {
+                let (boards, numbers) = game(rng);
+                (boards.iter().map(numbers_on).collect(), numbers)
+            },
+            shrink_game,
+            |(boards, numbers)| {
+                let boards: Vec<_> = boards.iter().map(|board| Board::new_from(board)).collect();
+                if !has_one_last_winner(&boards, numbers) {
+                    return Ok(());
+                }
+
+                prop::agree(
+                    part_two(boards.clone(), numbers),
+                    part_two_other(boards, numbers),
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let problems: Vec<_> = validate("1,x,3\n\n1 2 3 4 5\n1 2 3 4\n1 2 3 4 5\n")
+            .into_iter()
+            .map(|problem| (problem.line, problem.column, problem.expected))
+            .collect();
+
+        assert_eq!(
+            problems,
+            [
+                (1, 3, String::from("a drawn number")),
+                (4, 1, String::from("a row of 5 numbers")),
+                (5, 10, String::from("5 rows")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_boards() {
+        for input in ["7,4,9\n", "7,4,9\n\n\n"] {
+            let err = load_input(input).unwrap_err();
+
+            assert_eq!(
+                (err.line, err.column, err.expected.as_str()),
+                (1, 6, "at least one board")
+            );
+        }
+    }
+
+    #[test]
+    fn board_is_won() {
+        let mut board = Board::new_from(&[
+            22, 13, 17, 11, 0, 8, 2, 23, 4, 24, 21, 9, 14, 16, 7, 6, 10, 3, 18, 5, 1, 12, 20, 15,
+            19,
+        ]);
+
+        assert!(!board.is_completed_at(0));
+        assert!(!board.is_completed_at(16));
+        assert!(!board.is_completed_at(17));
+        assert!(!board.is_completed_at(18));
+
+        board.cells[0].marked = true;
+        board.cells[1].marked = true;
+        board.cells[2].marked = true;
+        board.cells[3].marked = true;
+        board.cells[4].marked = true;
+
+        assert!(board.is_completed_at(0));
+        assert!(!board.is_completed_at(16));
+        assert!(!board.is_completed_at(17));
+        assert!(!board.is_completed_at(18));
+
+        board.cells[7].marked = true;
+        board.cells[12].marked = true;
+        board.cells[17].marked = true;
+        board.cells[22].marked = true;
+
+        assert!(board.is_completed_at(0));
+        assert!(!board.is_completed_at(16));
+        assert!(board.is_completed_at(17));
+        assert!(!board.is_completed_at(18));
+
+        board.cells[8].marked = true;
+        board.cells[16].marked = true;
+        board.cells[20].marked = true;
+
+        assert!(board.is_completed_at(0));
+        assert!(board.is_completed_at(16));
+        assert!(board.is_completed_at(17));
+        assert!(!board.is_completed_at(18));
+
+        board.cells[6].marked = true;
+        board.cells[18].marked = true;
+        board.cells[24].marked = true;
+
+        assert!(board.is_completed_at(0));
+        assert!(board.is_completed_at(16));
+        assert!(board.is_completed_at(17));
+        assert!(board.is_completed_at(18));
+    }
+
+    #[test]
+    fn test_part_two_sample() {
+        let (boards, numbers) = load_input(&fixtures::input(4, "sample")).unwrap();
+
+        assert_eq!(part_two(boards, &numbers), Some(1924));
+    }
+
+    #[test]
+    fn test_no_board_wins() {
+        let sample = fixtures::input(4, "sample");
+        let (_, boards) = sample.split_once('\n').unwrap();
+        let game = load_input(&format!("7,4,9\n{}", boards)).unwrap();
+
+        assert_eq!(Day4::part_one(&game).to_string(), "none");
+        assert_eq!(Day4::part_two(&game).to_string(), "none");
+    }
+}
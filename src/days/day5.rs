@@ -1,8 +1,11 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fmt;
+
+use crate::{parse, ParseError, Solution};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Point {
+pub struct Point {
     x: i32,
     y: i32,
 }
@@ -53,7 +56,7 @@ enum LineType {
 }
 
 #[derive(Debug)]
-struct Line {
+pub struct Line {
     point_a: Point,
     point_b: Point,
     line_type: LineType,
@@ -131,9 +134,9 @@ impl Line {
         match line_type {
             LineType::Vertical { x } => {
                 let mut point = if smaller_start.y < bigger_start.y {
-                    bigger_start.clone()
+                    *bigger_start
                 } else {
-                    smaller_start.clone()
+                    *smaller_start
                 };
 
                 while point.is_on(a) && point.is_on(b) {
@@ -147,9 +150,9 @@ impl Line {
             }
             LineType::Horizontal { y } => {
                 let mut point = if smaller_start.x < bigger_start.x {
-                    bigger_start.clone()
+                    *bigger_start
                 } else {
-                    smaller_start.clone()
+                    *smaller_start
                 };
 
                 while point.is_on(a) && point.is_on(b) {
@@ -163,9 +166,9 @@ impl Line {
             }
             LineType::Diagonal { m, .. } => {
                 let mut point = if smaller_start.x <= bigger_start.x {
-                    bigger_start.clone()
+                    *bigger_start
                 } else {
-                    smaller_start.clone()
+                    *smaller_start
                 };
 
                 while point.is_on(a) && point.is_on(b) {
@@ -196,7 +199,7 @@ impl Line {
             }
             (Diagonal { m: m_a, b: b_a }, Diagonal { m: m_b, b: b_b }) => match (m_a, m_b) {
                 (Slope::Positive, Slope::Positive) | (Slope::Negative, Slope::Negative) => {
-                    match b_a.cmp(&b_b) {
+                    match b_a.cmp(b_b) {
                         Ordering::Equal => {
                             Line::intersections_same_line(self, other, &self.line_type)
                         }
@@ -250,12 +253,14 @@ impl Line {
     }
 }
 
-fn part_one(lines: &Vec<Line>) -> u32 {
+fn part_one(lines: &[Line]) -> u32 {
     let non_diag_lines: Vec<_> = lines
         .iter()
-        .filter(|line| match &line.line_type {
-            LineType::Horizontal { y: _ } | LineType::Vertical { x: _ } => true,
-            _ => false,
+        .filter(|line| {
+            matches!(
+                line.line_type,
+                LineType::Horizontal { .. } | LineType::Vertical { .. }
+            )
         })
         .collect();
 
@@ -266,7 +271,7 @@ fn part_one(lines: &Vec<Line>) -> u32 {
         for j in (i + 1)..non_diag_lines.len() {
             let other_line = &non_diag_lines[j];
 
-            for intersection in line.intersections(&other_line) {
+            for intersection in line.intersections(other_line) {
                 let intersection_record = intersections.entry(intersection).or_insert(0);
                 *intersection_record += 1;
             }
@@ -276,7 +281,7 @@ fn part_one(lines: &Vec<Line>) -> u32 {
     intersections.len() as u32
 }
 
-fn part_two(lines: &Vec<Line>) -> u32 {
+fn part_two(lines: &[Line]) -> u32 {
     let mut intersections: HashMap<Point, u32> = HashMap::new();
 
     for i in 0..lines.len() {
@@ -284,7 +289,7 @@ fn part_two(lines: &Vec<Line>) -> u32 {
         for j in (i + 1)..lines.len() {
             let other_line = &lines[j];
 
-            for intersection in line.intersections(&other_line) {
+            for intersection in line.intersections(other_line) {
                 let intersection_record = intersections.entry(intersection).or_insert(0);
                 *intersection_record += 1;
             }
@@ -294,82 +299,70 @@ fn part_two(lines: &Vec<Line>) -> u32 {
     intersections.len() as u32
 }
 
-fn load_input() -> Vec<Line> {
-    let input = include_str!("day5.txt");
+fn load_point(input: &str, point_str: &str) -> Result<Point, ParseError> {
+    let mut split = point_str.split(',');
+    let x = parse::next(input, point_str, &mut split, "`,`")?;
+    let y = parse::next(input, point_str, &mut split, "`,`")?;
+
+    Ok(Point {
+        x: parse::field(input, x, "an x coordinate")?,
+        y: parse::field(input, y, "a y coordinate")?,
+    })
+}
 
+fn load_input(input: &str) -> Result<Vec<Line>, ParseError> {
     input
         .lines()
-        .filter(|line_str| line_str.len() > 0)
+        .filter(|line_str| !line_str.is_empty())
         .map(|line_str| {
-            let components = line_str
-                .split(" -> ")
-                .flat_map(|split_str| split_str.split(','))
-                .map(|num_str| num_str.parse::<i32>().unwrap())
-                .collect::<Vec<_>>();
-
-            Line::new(
-                Point {
-                    x: components[0],
-                    y: components[1],
-                },
-                Point {
-                    x: components[2],
-                    y: components[3],
-                },
-            )
+            let mut split = line_str.split(" -> ");
+            let point_a = parse::next(input, line_str, &mut split, "`x,y`")?;
+            let point_b = parse::next(input, line_str, &mut split, "` -> `")?;
+
+            let point_a = load_point(input, point_a)?;
+            let point_b = load_point(input, point_b)?;
+
+            let is_straight = point_a.x == point_b.x || point_a.y == point_b.y;
+            if !is_straight && (point_a.x - point_b.x).abs() != (point_a.y - point_b.y).abs() {
+                return Err(ParseError::at(
+                    input,
+                    line_str,
+                    "a horizontal, vertical or diagonal line",
+                ));
+            }
+
+            Ok(Line::new(point_a, point_b))
         })
         .collect()
 }
 
-fn main() {
-    let input = load_input();
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const INPUT: &'static str = include_str!("day5.txt");
 
-    let part_one_answer = part_one(&input);
-    let part_two_answer = part_two(&input);
+    type Input = Vec<Line>;
 
-    println!("PART ONE ANSWER {}", part_one_answer);
-    println!("PART TWO ANSWER {}", part_two_answer);
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(lines: &Self::Input) -> impl fmt::Display {
+        part_one(lines)
+    }
+
+    fn part_two(lines: &Self::Input) -> impl fmt::Display {
+        part_two(lines)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fixtures::fixture_tests;
 
-    #[test]
-    fn test_part_one_sample() {
-        let lines = vec![
-            Line::new(Point { x: 0, y: 9 }, Point { x: 5, y: 9 }),
-            Line::new(Point { x: 8, y: 0 }, Point { x: 0, y: 8 }),
-            Line::new(Point { x: 9, y: 4 }, Point { x: 3, y: 4 }),
-            Line::new(Point { x: 2, y: 2 }, Point { x: 2, y: 1 }),
-            Line::new(Point { x: 7, y: 0 }, Point { x: 7, y: 4 }),
-            Line::new(Point { x: 6, y: 4 }, Point { x: 2, y: 0 }),
-            Line::new(Point { x: 0, y: 9 }, Point { x: 2, y: 9 }),
-            Line::new(Point { x: 3, y: 4 }, Point { x: 1, y: 4 }),
-            Line::new(Point { x: 0, y: 0 }, Point { x: 8, y: 8 }),
-            Line::new(Point { x: 5, y: 5 }, Point { x: 8, y: 2 }),
-        ];
-
-        assert_eq!(part_one(&lines), 5);
-    }
-
-    #[test]
-    fn test_part_two_sample() {
-        let lines = vec![
-            Line::new(Point { x: 0, y: 9 }, Point { x: 5, y: 9 }),
-            Line::new(Point { x: 8, y: 0 }, Point { x: 0, y: 8 }),
-            Line::new(Point { x: 9, y: 4 }, Point { x: 3, y: 4 }),
-            Line::new(Point { x: 2, y: 2 }, Point { x: 2, y: 1 }),
-            Line::new(Point { x: 7, y: 0 }, Point { x: 7, y: 4 }),
-            Line::new(Point { x: 6, y: 4 }, Point { x: 2, y: 0 }),
-            Line::new(Point { x: 0, y: 9 }, Point { x: 2, y: 9 }),
-            Line::new(Point { x: 3, y: 4 }, Point { x: 1, y: 4 }),
-            Line::new(Point { x: 0, y: 0 }, Point { x: 8, y: 8 }),
-            Line::new(Point { x: 5, y: 5 }, Point { x: 8, y: 2 }),
-        ];
-
-        assert_eq!(part_two(&lines), 12);
-    }
+    fixture_tests!(Day5);
 
     #[test]
     fn horizontal_lines() {
@@ -391,11 +384,6 @@ mod tests {
         let hor_line = Line::new(Point { x: 0, y: 9 }, Point { x: 5, y: 9 });
         let point = Point { x: 1, y: 9 };
 
-        assert_eq!(point.is_on(&hor_line), true);
-    }
-
-    #[test]
-    fn test_part_one() {
-        assert_eq!(part_one(&load_input()), 7297);
+        assert!(point.is_on(&hor_line));
     }
 }
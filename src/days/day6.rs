@@ -0,0 +1,194 @@
+use std::fmt;
+use std::iter;
+
+use colored::Color;
+
+use crate::animate::{Cell, Frame, Frames};
+use crate::grid::Grid;
+use crate::{parse, ParseError, Solution};
+
+#[derive(Debug, Copy, Clone)]
+pub struct LFish {
+    timer: u32,
+}
+
+impl LFish {
+    fn spawn() -> LFish {
+        LFish { timer: 8 }
+    }
+}
+
+fn part_one(mut fish: Vec<LFish>, days: u32) -> u32 {
+    for _ in 0..days {
+        let mut next_fish = Vec::new();
+        for f in &mut fish {
+            if f.timer == 0 {
+                next_fish.push(LFish::spawn());
+                f.timer = 6;
+            } else {
+                f.timer -= 1;
+            }
+        }
+
+        fish.append(&mut next_fish);
+    }
+
+    fish.len() as u32
+}
+
+/// How many fish there are with each timer.
+fn count_timers(fish: &[LFish]) -> [u64; 9] {
+    let mut counts = [0u64; 9];
+    for f in fish {
+        counts[f.timer as usize] += 1;
+    }
+
+    counts
+}
+
+/// Moves the counts from [`count_timers`] on a day.
+fn age(counts: &mut [u64; 9]) {
+    counts.rotate_left(1);
+    counts[6] += counts[8];
+}
+
+/// A bar for each timer, as long as the share of the school with it, over
+/// 256 days.
+pub(crate) fn frames(input: &str) -> Result<Frames, ParseError> {
+    const BAR: usize = 50;
+    let counts = count_timers(&load_input(input)?);
+
+    let frames = iter::successors(Some((0, counts)), |(day, counts)| {
+        let mut counts = *counts;
+        age(&mut counts);
+        (*day < 256).then_some((day + 1, counts))
+    })
+    .map(|(day, counts)| {
+        let total: u64 = counts.iter().sum();
+        let cells = Grid::from_fn(BAR + 2, 9, |pos| {
+            let filled = (counts[pos.y] * BAR as u64).checked_div(total).unwrap_or(0);
+            match pos.x {
+                0 => Cell::plain(char::from_digit(pos.y as u32, 10).unwrap()),
+                1 => Cell::plain(' '),
+                x if ((x - 2) as u64) < filled && pos.y == 8 => {
+                    Cell::colored('█', Color::BrightGreen)
+                }
+                x if ((x - 2) as u64) < filled => Cell::colored('█', Color::Green),
+                _ => Cell::plain(' '),
+            }
+        });
+
+        Frame {
+            caption: format!("day {}: {} fish", day, total),
+            cells,
+        }
+    });
+
+    Ok(Box::new(frames))
+}
+
+fn load_input(input: &str) -> Result<Vec<LFish>, ParseError> {
+    input
+        .lines()
+        .flat_map(|line| line.split(','))
+        .filter(|fish_str| !fish_str.is_empty())
+        .map(|fish_str| {
+            let timer: u32 = parse::field(input, fish_str, "a timer")?;
+            if timer > 8 {
+                return Err(ParseError::at(input, fish_str, "a timer from 0 to 8"));
+            }
+            Ok(LFish { timer })
+        })
+        .collect()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const INPUT: &'static str = include_str!("day6.txt");
+
+    type Input = Vec<LFish>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(fish: &Self::Input) -> impl fmt::Display {
+        part_one(fish.clone(), 80)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, fixture_tests};
+    use crate::prop;
+
+    fixture_tests!(Day6);
+
+    type School = (Vec<LFish>, u32);
+
+    /// Counts the fish by timer instead of simulating each one, as every fish
+    /// with the same timer does the same thing.
+    fn count_fish(fish: &[LFish], days: u32) -> u64 {
+        let mut counts = count_timers(fish);
+
+        for _ in 0..days {
+            age(&mut counts);
+        }
+
+        counts.iter().sum()
+    }
+
+    fn shrink_school((fish, days): &School) -> Vec<School> {
+        let shrink_fish = |f: &LFish| {
+            prop::shrink_number(f.timer)
+                .into_iter()
+                .map(|timer| LFish { timer })
+                .collect()
+        };
+
+        let fewer_fish = prop::shrink_vec(fish, shrink_fish)
+            .into_iter()
+            .map(|fish| (fish, *days));
+        let fewer_days = prop::shrink_number(*days)
+            .into_iter()
+            .map(|days| (fish.clone(), days));
+
+        fewer_fish.chain(fewer_days).collect()
+    }
+
+    #[test]
+    fn test_simulation_and_counts_agree() {
+        let school = prop::generated(6, 10, load_input);
+
+        prop::check(
+            |rng| (school(rng), rng.between(0, 60) as u32),
+            shrink_school,
+            |(fish, days)| {
+                prop::agree(
+                    part_one(fish.clone(), *days) as u64,
+                    count_fish(fish, *days),
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn test_long_timers() {
+        let err = load_input("3,9,1\n").unwrap_err();
+
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (1, 3, "a timer from 0 to 8")
+        );
+    }
+
+    #[test]
+    fn test_part_one_sample() {
+        let fish = load_input(&fixtures::input(6, "sample")).unwrap();
+
+        assert_eq!(part_one(fish, 18), 26);
+    }
+}
@@ -0,0 +1,116 @@
+use std::fmt;
+
+use crate::{parse, ParseError, Solution};
+
+fn part_one(positions: &[i32]) -> i32 {
+    let min_best = positions.iter().min().unwrap();
+    let max_best = positions.iter().max().unwrap();
+
+    let mut best_movements: Option<i32> = None;
+
+    for i in *min_best..=*max_best {
+        let mut movements = 0;
+        positions
+            .iter()
+            .for_each(|pos| movements += if i > *pos { i - *pos } else { *pos - i });
+        if let Some(best) = best_movements {
+            best_movements = if movements < best {
+                Some(movements)
+            } else {
+                Some(best)
+            };
+        } else {
+            best_movements = Some(movements);
+        }
+    }
+
+    best_movements.unwrap()
+}
+
+fn triangular_number(num: i32) -> i32 {
+    (num * (num + 1)) / 2
+}
+
+fn part_two(positions: &[i32]) -> i32 {
+    let min_best = positions.iter().min().unwrap();
+    let max_best = positions.iter().max().unwrap();
+
+    let mut best_movements: Option<i32> = None;
+
+    for i in *min_best..=*max_best {
+        let mut movements = 0;
+        positions.iter().for_each(|pos| {
+            movements += if i > *pos {
+                triangular_number(i - *pos)
+            } else {
+                triangular_number(*pos - i)
+            }
+        });
+        if let Some(best) = best_movements {
+            best_movements = if movements < best {
+                Some(movements)
+            } else {
+                Some(best)
+            };
+        } else {
+            best_movements = Some(movements);
+        }
+    }
+
+    best_movements.unwrap()
+}
+
+fn load_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    let positions = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .flat_map(|line| line.split(','))
+        .map(|num| parse::field(input, num, "a position"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if positions.is_empty() {
+        return Err(ParseError::at(input, input, "a position"));
+    }
+
+    Ok(positions)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const INPUT: &'static str = include_str!("day7.txt");
+
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(positions: &Self::Input) -> impl fmt::Display {
+        part_one(positions)
+    }
+
+    fn part_two(positions: &Self::Input) -> impl fmt::Display {
+        part_two(positions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::fixture_tests;
+
+    fixture_tests!(Day7);
+
+    #[test]
+    fn test_one_position() {
+        for input in ["12\n", "2000\n", "3,3,3\n"] {
+            let positions = load_input(input).unwrap();
+
+            assert_eq!((part_one(&positions), part_two(&positions)), (0, 0));
+        }
+        assert_eq!(part_one(&[0, 4]), 4);
+        assert_eq!(part_two(&[0, 4]), 6);
+    }
+}
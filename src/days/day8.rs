@@ -0,0 +1,370 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{parse, ParseError, Solution};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Segment {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DigitRender {
+    segment_str: String,
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug)]
+pub struct Entry {
+    sig_patterns: Vec<DigitRender>,
+    output_vals: Vec<DigitRender>,
+}
+
+fn load_render(input: &str, sig: &str) -> Result<DigitRender, ParseError> {
+    let mut segments = sig
+        .char_indices()
+        .map(|(i, c)| match c {
+            'a' => Ok(Segment::A),
+            'b' => Ok(Segment::B),
+            'c' => Ok(Segment::C),
+            'd' => Ok(Segment::D),
+            'e' => Ok(Segment::E),
+            'f' => Ok(Segment::F),
+            'g' => Ok(Segment::G),
+            _ => Err(ParseError::at(
+                input,
+                &sig[i..],
+                "a segment from `a` to `g`",
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    segments.sort();
+
+    let mut chars: Vec<_> = sig.chars().collect();
+    chars.sort();
+
+    Ok(DigitRender {
+        segment_str: String::from_iter(chars.iter()),
+        segments,
+    })
+}
+
+/// A problem with the number of renders on one side of an entry's `|`.
+fn count_problem(input: &str, side: &str, count: usize, expected: &str) -> Option<ParseError> {
+    (side.split_whitespace().count() != count)
+        .then(|| ParseError::at(input, side.trim_start(), expected))
+}
+
+/// The first signal pattern that shows up twice, as each digit has its own.
+fn repeated_pattern(input: &str, patterns: &str) -> Option<ParseError> {
+    let patterns: Vec<_> = patterns.split_whitespace().collect();
+
+    patterns.iter().enumerate().find_map(|(i, pattern)| {
+        let sorted = |pattern: &str| {
+            let mut chars: Vec<_> = pattern.chars().collect();
+            chars.sort();
+            chars
+        };
+        patterns[..i]
+            .iter()
+            .any(|earlier| sorted(earlier) == sorted(pattern))
+            .then(|| ParseError::at(input, pattern, "a pattern for a different digit"))
+    })
+}
+
+fn load_entry(input: &str, line: &str) -> Result<Entry, ParseError> {
+    let mut split = line.split('|');
+    let patterns = parse::next(input, line, &mut split, "signal patterns")?;
+    let outputs = parse::next(input, line, &mut split, "`|`")?;
+
+    let sig_patterns = patterns
+        .split_whitespace()
+        .map(|sig| load_render(input, sig))
+        .collect::<Result<Vec<_>, _>>()?;
+    let output_vals = outputs
+        .split_whitespace()
+        .map(|val| load_render(input, val))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let problem = count_problem(input, patterns, 10, "10 signal patterns")
+        .or_else(|| count_problem(input, outputs, 4, "4 output values"))
+        .or_else(|| repeated_pattern(input, patterns));
+    if let Some(problem) = problem {
+        return Err(problem);
+    }
+
+    let entry = Entry {
+        sig_patterns,
+        output_vals,
+    };
+    let map = build_sig_map(&entry).ok_or_else(|| {
+        ParseError::at(input, patterns.trim_start(), "patterns for the ten digits")
+    })?;
+    if let Some((output, _)) = outputs
+        .split_whitespace()
+        .zip(&entry.output_vals)
+        .find(|(_, val)| !map.contains_key(&val.segment_str))
+    {
+        return Err(ParseError::at(input, output, "one of the signal patterns"));
+    }
+
+    Ok(entry)
+}
+
+fn load_input(input: &str) -> Result<Vec<Entry>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| load_entry(input, line))
+        .collect()
+}
+
+fn part_one(entries: &[Entry]) -> u32 {
+    entries
+        .iter()
+        .flat_map(|entry| &entry.output_vals)
+        .filter(|out_val| matches!(out_val.segments.len(), 2 | 3 | 4 | 7))
+        .count() as u32
+}
+
+/// Works out which digit each of the entry's patterns lights up, or `None`
+/// if they don't light up the ten digits.
+fn build_sig_map(entry: &Entry) -> Option<HashMap<String, u32>> {
+    let mut sig_map = HashMap::new();
+
+    let mut sig_seven = None;
+    for signal in &entry.sig_patterns {
+        match signal.segments.len() {
+            2 => {
+                sig_map.insert(signal.segment_str.clone(), 1);
+            }
+            3 => {
+                sig_seven = Some(signal);
+                sig_map.insert(signal.segment_str.clone(), 7);
+            }
+            4 => {
+                sig_map.insert(signal.segment_str.clone(), 4);
+            }
+            7 => {
+                sig_map.insert(signal.segment_str.clone(), 8);
+            }
+            _ => (),
+        }
+    }
+    let mut signals = entry.sig_patterns.clone();
+
+    let sig_seven = sig_seven?;
+    signals.retain(|sig| !sig_map.contains_key(&sig.segment_str));
+
+    let i = signals.iter().position(|sig| {
+        sig.segments.len() == 6
+            && sig_seven
+                .segments
+                .iter()
+                .any(|sig_seg| !sig.segments.contains(sig_seg))
+    })?;
+    let sig_six = signals.swap_remove(i);
+    sig_map.insert(sig_six.segment_str.clone(), 6);
+
+    let i = signals.iter().position(|sig| {
+        sig.segments.len() == 5
+            && sig
+                .segments
+                .iter()
+                .all(|sig_seg| sig_six.segments.contains(sig_seg))
+    })?;
+    let sig_five = signals.swap_remove(i);
+    sig_map.insert(sig_five.segment_str.clone(), 5);
+
+    let i = signals.iter().position(|sig| {
+        sig.segments.len() == 6
+            && sig_five
+                .segments
+                .iter()
+                .all(|sig_seg| sig.segments.contains(sig_seg))
+    })?;
+    let sig_nine = signals.swap_remove(i);
+    sig_map.insert(sig_nine.segment_str.clone(), 9);
+
+    let i = signals.iter().position(|sig| {
+        sig.segments.len() == 5
+            && sig_seven
+                .segments
+                .iter()
+                .all(|sig_seg| sig.segments.contains(sig_seg))
+    })?;
+    let sig_three = signals.swap_remove(i);
+    sig_map.insert(sig_three.segment_str.clone(), 3);
+
+    let i = signals.iter().position(|sig| sig.segments.len() == 5)?;
+    let sig_two = signals.swap_remove(i);
+    sig_map.insert(sig_two.segment_str.clone(), 2);
+
+    let sig_zero = signals.pop()?;
+    sig_map.insert(sig_zero.segment_str.clone(), 0);
+
+    Some(sig_map)
+}
+
+fn part_two(entries: &[Entry]) -> u32 {
+    let mut sum = 0;
+
+    for entry in entries {
+        let map = build_sig_map(entry).expect("the patterns were checked while parsing");
+        let mut entry_sum = 0;
+        for (i, output_val) in entry.output_vals.iter().enumerate() {
+            let num = map[&output_val.segment_str];
+            let pow = entry.output_vals.len() - i - 1;
+            entry_sum += num * 10u32.pow(pow as u32);
+        }
+        sum += entry_sum;
+    }
+
+    sum
+}
+
+/// Every entry that doesn't have 10 patterns and 4 output values, and every
+/// segment that isn't one, rather than just the first. Entries without any
+/// of those get the rest of the parser's checks.
+pub(crate) fn validate(input: &str) -> Vec<ParseError> {
+    let mut problems = vec![];
+
+    for line in input.lines().filter(|line| !line.is_empty()) {
+        let Some((patterns, outputs)) = line.split_once('|') else {
+            problems.push(ParseError::after(input, line, "`|`"));
+            continue;
+        };
+        let found = problems.len();
+
+        for (side, count, expected) in [
+            (patterns, 10, "10 signal patterns"),
+            (outputs, 4, "4 output values"),
+        ] {
+            problems.extend(count_problem(input, side, count, expected));
+            for render in side.split_whitespace() {
+                if let Some((i, _)) = render
+                    .char_indices()
+                    .find(|(_, c)| !('a'..='g').contains(c))
+                {
+                    problems.push(ParseError::at(
+                        input,
+                        &render[i..],
+                        "a segment from `a` to `g`",
+                    ));
+                }
+            }
+        }
+        if problems.len() == found {
+            problems.extend(load_entry(input, line).err());
+        }
+    }
+
+    problems
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const INPUT: &'static str = include_str!("day8.txt");
+
+    type Input = Vec<Entry>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(entries: &Self::Input) -> impl fmt::Display {
+        part_one(entries)
+    }
+
+    fn part_two(entries: &Self::Input) -> impl fmt::Display {
+        part_two(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, fixture_tests};
+
+    fixture_tests!(Day8);
+
+    #[test]
+    fn test_validate() {
+        let problems: Vec<_> = validate("ab ch | ef\nxyz\n")
+            .into_iter()
+            .map(|problem| (problem.line, problem.column, problem.expected))
+            .collect();
+
+        assert_eq!(
+            problems,
+            [
+                (1, 1, String::from("10 signal patterns")),
+                (1, 5, String::from("a segment from `a` to `g`")),
+                (1, 9, String::from("4 output values")),
+                (2, 4, String::from("`|`")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_sig_map() {
+        let input = load_input(&fixtures::input(8, "sample2")).unwrap();
+
+        let map = build_sig_map(&input[0]).unwrap();
+        assert_eq!(map.get("abcdefg"), Some(&8));
+        assert_eq!(map.get("bcdef"), Some(&5));
+        assert_eq!(map.get("acdfg"), Some(&2));
+        assert_eq!(map.get("abcdf"), Some(&3));
+        assert_eq!(map.get("abd"), Some(&7));
+        assert_eq!(map.get("abcdef"), Some(&9));
+        assert_eq!(map.get("bcdefg"), Some(&6));
+        assert_eq!(map.get("abef"), Some(&4));
+        assert_eq!(map.get("abcdeg"), Some(&0));
+        assert_eq!(map.get("ab"), Some(&1));
+    }
+
+    #[test]
+    fn test_entries_that_dont_resolve() {
+        let sample = fixtures::input(8, "sample2");
+        let (patterns, outputs) = sample.trim_end().split_once(" | ").unwrap();
+        let entry = |patterns: &str, outputs: &str| format!("{} | {}\n", patterns, outputs);
+
+        for (input, expected) in [
+            (entry("ab", outputs), "10 signal patterns"),
+            (entry(patterns, "ab"), "4 output values"),
+            (
+                entry(&patterns.replace("dab", "ba"), outputs),
+                "a pattern for a different digit",
+            ),
+            (
+                entry(&patterns.replace("dab", "cd"), outputs),
+                "patterns for the ten digits",
+            ),
+            (
+                entry(patterns, &outputs.replacen("cdfeb", "acdeg", 1)),
+                "one of the signal patterns",
+            ),
+        ] {
+            let err = load_input(&input).unwrap_err();
+
+            assert_eq!(err.expected, expected, "{}", input);
+            assert_eq!(validate(&input), vec![err], "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_bad_segment() {
+        let err = load_input("ab cdx | ab").unwrap_err();
+
+        assert_eq!((err.line, err.column), (1, 6));
+        assert_eq!(err.expected, "a segment from `a` to `g`");
+    }
+}
@@ -0,0 +1,151 @@
+use std::fmt;
+
+use crate::grid::{Grid, Pos};
+use crate::{ParseError, Solution};
+
+fn load_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    let heights = Grid::digits(input, input)?;
+
+    if basin_sizes(&heights).len() < 3 {
+        return Err(too_few_basins(input));
+    }
+
+    Ok(heights)
+}
+
+/// Part two multiplies the sizes of the three biggest basins, so there have
+/// to be that many.
+fn too_few_basins(input: &str) -> ParseError {
+    ParseError::after(input, input.trim_end(), "at least three basins")
+}
+
+/// Every height that isn't a digit and every row that's wider or narrower
+/// than the first, rather than just the first, or once they're all right,
+/// too few basins.
+pub(crate) fn validate(input: &str) -> Vec<ParseError> {
+    let mut problems = vec![];
+    let rows: Vec<_> = input.lines().filter(|line| !line.is_empty()).collect();
+    let width = rows.first().map_or(0, |row| row.chars().count());
+
+    for row in &rows {
+        for (i, c) in row.char_indices() {
+            if !c.is_ascii_digit() {
+                problems.push(ParseError::at(input, &row[i..], "a digit"));
+            }
+        }
+        if row.chars().count() != width {
+            problems.push(ParseError::at(
+                input,
+                row,
+                format!("a row {} cells wide", width),
+            ));
+        }
+    }
+    if problems.is_empty() {
+        if let Ok(heights) = Grid::digits(input, input) {
+            if basin_sizes(&heights).len() < 3 {
+                problems.push(too_few_basins(input));
+            }
+        }
+    }
+
+    problems
+}
+
+fn is_low_point(heights: &Grid<u32>, pos: Pos) -> bool {
+    heights
+        .neighbours4(pos)
+        .all(|adj_pos| heights[adj_pos] > heights[pos])
+}
+
+fn part_one(heights: &Grid<u32>) -> u32 {
+    heights
+        .positions()
+        .filter(|pos| is_low_point(heights, *pos))
+        .map(|pos| 1 + heights[pos])
+        .sum()
+}
+
+fn basin_size(heights: &Grid<u32>, seen: &mut Grid<bool>, start: Pos) -> usize {
+    let mut size = 0;
+    let mut to_visit = vec![start];
+    seen[start] = true;
+
+    while let Some(pos) = to_visit.pop() {
+        size += 1;
+
+        for adj_pos in heights.neighbours4(pos) {
+            if !seen[adj_pos] && heights[adj_pos] != 9 {
+                seen[adj_pos] = true;
+                to_visit.push(adj_pos);
+            }
+        }
+    }
+
+    size
+}
+
+/// The size of every basin, smallest first. The basins are the areas the 9s
+/// fence off.
+pub(crate) fn basin_sizes(heights: &Grid<u32>) -> Vec<usize> {
+    let mut seen = heights.map(|height| *height == 9);
+    let mut basin_sizes = Vec::new();
+
+    for pos in heights.positions() {
+        if !seen[pos] {
+            basin_sizes.push(basin_size(heights, &mut seen, pos));
+        }
+    }
+    basin_sizes.sort();
+
+    basin_sizes
+}
+
+fn part_two(heights: &Grid<u32>) -> u32 {
+    basin_sizes(heights)
+        .iter()
+        .rev()
+        .take(3)
+        .map(|size| *size as u32)
+        .product()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const INPUT: &'static str = include_str!("day9.txt");
+
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_input(input)
+    }
+
+    fn part_one(heights: &Self::Input) -> impl fmt::Display {
+        part_one(heights)
+    }
+
+    fn part_two(heights: &Self::Input) -> impl fmt::Display {
+        part_two(heights)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::fixture_tests;
+
+    fixture_tests!(Day9);
+
+    #[test]
+    fn test_too_few_basins() {
+        for input in ["094\n", "19\n91\n", "1291\n2999\n"] {
+            let err = load_input(input).unwrap_err();
+
+            assert_eq!(err.expected, "at least three basins", "{:?}", input);
+            assert_eq!(validate(input), vec![err]);
+        }
+        assert!(load_input("191\n999\n191\n").is_ok());
+    }
+}
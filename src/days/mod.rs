@@ -0,0 +1,86 @@
+use crate::Day;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub use day1::Day1;
+pub use day2::Day2;
+pub use day3::Day3;
+pub use day4::Day4;
+pub use day5::Day5;
+pub use day6::Day6;
+pub use day7::Day7;
+pub use day8::Day8;
+pub use day9::Day9;
+pub use day10::Day10;
+pub use day11::Day11;
+pub use day12::Day12;
+pub use day13::Day13;
+pub use day14::Day14;
+pub use day15::Day15;
+pub use day16::Day16;
+pub use day17::Day17;
+pub use day18::Day18;
+pub use day19::Day19;
+pub use day20::Day20;
+pub use day21::Day21;
+pub use day22::Day22;
+pub use day23::Day23;
+pub use day24::Day24;
+pub use day25::Day25;
+
+/// Every day of the calendar so far, in order.
+pub const ALL: &[Day] = &[
+    Day::of::<Day1>(),
+    Day::of::<Day2>(),
+    Day::of::<Day3>(),
+    Day::of::<Day4>(),
+    Day::of::<Day5>(),
+    Day::of::<Day6>(),
+    Day::of::<Day7>(),
+    Day::of::<Day8>(),
+    Day::of::<Day9>(),
+    Day::of::<Day10>(),
+    Day::of::<Day11>(),
+    Day::of::<Day12>(),
+    Day::of::<Day13>(),
+    Day::of::<Day14>(),
+    Day::of::<Day15>(),
+    Day::of::<Day16>(),
+    Day::of::<Day17>(),
+    Day::of::<Day18>(),
+    Day::of::<Day19>(),
+    Day::of::<Day20>(),
+    Day::of::<Day21>(),
+    Day::of::<Day22>(),
+    Day::of::<Day23>(),
+    Day::of::<Day24>(),
+    Day::of::<Day25>(),
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    ALL.iter().find(|day| day.number == number)
+}
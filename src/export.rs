@@ -0,0 +1,248 @@
+use std::fmt::Write;
+use std::str::FromStr;
+
+use crate::days::{day13, day15, day20};
+use crate::grid::{Grid, Pos};
+use crate::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(220, 40, 40);
+
+    /// A shade of gray, from black at 0 to white at `max`.
+    pub fn gray(value: u32, max: u32) -> Rgb {
+        let shade = (value.min(max) * 255 / max.max(1)) as u8;
+        Rgb(shade, shade, shade)
+    }
+
+    /// Whether the color is closer to black than white, for formats that
+    /// only have the two.
+    fn is_dark(&self) -> bool {
+        let Rgb(r, g, b) = *self;
+        // the usual luma weights, scaled up to whole numbers
+        299 * u32::from(r) + 587 * u32::from(g) + 114 * u32::from(b) < 128 * 1000
+    }
+
+    fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// A named image of a day's results, one color per cell of a grid.
+#[derive(Debug, Clone)]
+pub struct Picture {
+    pub name: &'static str,
+    pub pixels: Grid<Rgb>,
+}
+
+impl Picture {
+    /// Black where `grid` is true and white elsewhere, like ink on paper.
+    pub fn ink(name: &'static str, grid: &Grid<bool>) -> Picture {
+        Picture {
+            name,
+            pixels: grid.map(|set| if *set { Rgb::BLACK } else { Rgb::WHITE }),
+        }
+    }
+
+    /// Paints `path` over the picture in `color`. Steps off the picture are
+    /// left out.
+    pub fn highlight(mut self, path: &[Pos], color: Rgb) -> Picture {
+        for pos in path {
+            if let Some(pixel) = self.pixels.get_mut(*pos) {
+                *pixel = color;
+            }
+        }
+
+        self
+    }
+}
+
+/// The image formats pictures can be written in. The netpbm ones are the
+/// plain text variants, which are easy to diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Black and white.
+    Pbm,
+    Ppm,
+    /// Scalable, with each cell drawn as a square of `SVG_SCALE` pixels.
+    Svg,
+}
+
+/// How many pixels wide each cell is drawn in an SVG.
+const SVG_SCALE: usize = 4;
+
+impl Format {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Format::Pbm => "pbm",
+            Format::Ppm => "ppm",
+            Format::Svg => "svg",
+        }
+    }
+
+    pub fn write(&self, picture: &Picture) -> String {
+        match self {
+            Format::Pbm => pbm(&picture.pixels),
+            Format::Ppm => ppm(&picture.pixels),
+            Format::Svg => svg(&picture.pixels),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "pbm" => Ok(Format::Pbm),
+            "ppm" => Ok(Format::Ppm),
+            "svg" => Ok(Format::Svg),
+            _ => Err(format!("`{}` is not a format, expected pbm, ppm or svg", s)),
+        }
+    }
+}
+
+fn pbm(pixels: &Grid<Rgb>) -> String {
+    let mut text = format!("P1\n{} {}\n", pixels.width(), pixels.height());
+
+    for row in pixels.rows() {
+        let bits: Vec<_> = row
+            .iter()
+            .map(|pixel| if pixel.is_dark() { "1" } else { "0" })
+            .collect();
+        text += &bits.join(" ");
+        text.push('\n');
+    }
+
+    text
+}
+
+fn ppm(pixels: &Grid<Rgb>) -> String {
+    let mut text = format!("P3\n{} {}\n255\n", pixels.width(), pixels.height());
+
+    for row in pixels.rows() {
+        let values: Vec<_> = row
+            .iter()
+            .map(|Rgb(r, g, b)| format!("{} {} {}", r, g, b))
+            .collect();
+        text += &values.join("  ");
+        text.push('\n');
+    }
+
+    text
+}
+
+fn svg(pixels: &Grid<Rgb>) -> String {
+    let (width, height) = (pixels.width(), pixels.height());
+    let mut text = String::new();
+
+    writeln!(
+        text,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}" shape-rendering="crispEdges">"#,
+        width * SVG_SCALE,
+        height * SVG_SCALE,
+        width,
+        height
+    )
+    .unwrap();
+
+    for (y, row) in pixels.rows().enumerate() {
+        // neighbouring cells of the same color share a rectangle, which keeps
+        // the file to a handful of elements a row
+        let mut x = 0;
+        for run in row.chunk_by(|a, b| a == b) {
+            writeln!(
+                text,
+                r#"<rect x="{}" y="{}" width="{}" height="1" fill="{}"/>"#,
+                x,
+                y,
+                run.len(),
+                run[0].hex()
+            )
+            .unwrap();
+            x += run.len();
+        }
+    }
+
+    text + "</svg>\n"
+}
+
+/// The pictures a day can draw of its results.
+#[derive(Debug, Clone, Copy)]
+pub struct Export {
+    pub day: u8,
+    pictures: fn(&str) -> Result<Vec<Picture>, ParseError>,
+}
+
+impl Export {
+    /// Parses `input` and draws each picture from it.
+    pub fn pictures(&self, input: &str) -> Result<Vec<Picture>, ParseError> {
+        (self.pictures)(input)
+    }
+}
+
+pub const ALL: &[Export] = &[
+    Export {
+        day: 13,
+        pictures: day13::pictures,
+    },
+    Export {
+        day: 15,
+        pictures: day15::pictures,
+    },
+    Export {
+        day: 20,
+        pictures: day20::pictures,
+    },
+];
+
+pub fn get(day: u8) -> Option<&'static Export> {
+    ALL.iter().find(|export| export.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picture() -> Picture {
+        let grid = Grid::from_fn(3, 2, |pos| pos.x == pos.y);
+
+        Picture::ink("diagonal", &grid).highlight(&[Pos::new(2, 1), Pos::new(3, 3)], Rgb::RED)
+    }
+
+    #[test]
+    fn test_pbm() {
+        assert_eq!(Format::Pbm.write(&picture()), "P1\n3 2\n1 0 0\n0 1 1\n");
+    }
+
+    #[test]
+    fn test_ppm() {
+        assert_eq!(
+            Format::Ppm.write(&picture()),
+            "P3\n3 2\n255\n\
+             0 0 0  255 255 255  255 255 255\n\
+             255 255 255  0 0 0  220 40 40\n"
+        );
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = Format::Svg.write(&picture());
+
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="12" height="8""#));
+        assert!(svg.contains(r##"<rect x="1" y="0" width="2" height="1" fill="#ffffff"/>"##));
+        assert!(svg.contains(r##"<rect x="2" y="1" width="1" height="1" fill="#dc2828"/>"##));
+        assert_eq!(svg.matches("<rect").count(), 5);
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("svg".parse(), Ok(Format::Svg));
+        assert!("png".parse::<Format>().is_err());
+    }
+}
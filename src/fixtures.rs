@@ -0,0 +1,231 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::answers::{self, Answers, Verdict};
+use crate::{Day, Part, Solution};
+
+/// The answers to the sample inputs under `fixtures/`.
+const ANSWERS: &str = include_str!("../fixtures/answers.txt");
+
+/// Days without any samples, and why.
+const WITHOUT_SAMPLES: &[(u8, &str)] = &[
+    (
+        3,
+        "the puzzle's sample numbers are 5 bits long, and the solver only reads 12 bit ones",
+    ),
+    (
+        19,
+        "the puzzle's sample scanners haven't been copied in yet",
+    ),
+    (
+        24,
+        "the puzzle has no sample, only the MONAD program in the input",
+    ),
+];
+
+/// Parts of samples left without an answer, and why. Every other part a day
+/// has solved needs an answer for each of the day's samples.
+const LEFT_OUT: &[(u8, Part, &str, &str)] = &[
+    (
+        4,
+        Part::One,
+        "sample",
+        "the solver lets boards win on a diagonal, which the puzzle doesn't, and one of the \
+         sample boards does so early",
+    ),
+    (
+        16,
+        Part::Two,
+        "sample",
+        "the puzzle only gives part one's answer for this transmission",
+    ),
+    (
+        16,
+        Part::One,
+        "sample2",
+        "the puzzle only gives part two's answer for this transmission",
+    ),
+];
+
+fn is_left_out(day: u8, part: Part, name: &str) -> bool {
+    LEFT_OUT.iter().any(|(left_day, left_part, left_name, _)| {
+        (*left_day, *left_part, *left_name) == (day, part, name)
+    })
+}
+
+/// Where the sample inputs are kept, one directory per sample name, each
+/// holding a `dayN.txt` for every day that has a sample by that name.
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures")
+}
+
+/// The sample called `name` for `day`, for tests that go further than the
+/// answers do.
+pub fn input(day: u8, name: &str) -> String {
+    let path = root().join(name).join(format!("day{}.txt", day));
+
+    fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("couldn't read {}: {}", path.display(), err))
+}
+
+/// The names of every sample `day` has, in order.
+fn samples(day: u8) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(root())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.join(format!("day{}.txt", day)).is_file())
+        .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+
+    names
+}
+
+/// Solves both parts of `input` and compares them with the answers filed
+/// under `name`, returning how many were checked, a line for every one that
+/// didn't match and the solved parts that have no answer.
+fn check(day: &Day, input: &str, name: &str, answers: &Answers) -> (usize, Vec<String>, Vec<Part>) {
+    let parsed = day.parse(input).unwrap_or_else(|err| {
+        panic!(
+            "day {} couldn't parse `{}`:\n{}",
+            day.number,
+            name,
+            err.annotate(input)
+        )
+    });
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    let mut unanswered = Vec::new();
+
+    for part in Part::BOTH {
+        let answer = day.answer(part, &parsed);
+        if answers.get(day.number, part, name).is_none() {
+            if answer.solved {
+                unanswered.push(part);
+            }
+            continue;
+        }
+
+        if let Verdict::Fail { expected } = answers.check(day.number, part, name, &answer.text) {
+            failures.push(format!(
+                "day {} part {} on `{}`: expected {:?}, got {:?}",
+                day.number, part, name, expected, answer.text
+            ));
+        }
+        checked += 1;
+    }
+
+    (checked, failures, unanswered)
+}
+
+/// Checks `S` against every sample it has, each of which needs an answer for
+/// every part `S` has solved, short of the ones [`LEFT_OUT`]. Only the days
+/// listed in [`WITHOUT_SAMPLES`] can go without.
+pub fn check_samples<S: Solution>()
+where
+    S::Input: 'static,
+{
+    let day = Day::of::<S>();
+    let answers = Answers::parse(ANSWERS).unwrap();
+    let names = samples(day.number);
+    let without = WITHOUT_SAMPLES
+        .iter()
+        .any(|(number, _)| *number == day.number);
+    let mut failures = Vec::new();
+
+    assert!(
+        names.is_empty() == without,
+        "day {} {}",
+        day.number,
+        if without {
+            "has samples, so it shouldn't be listed as without them"
+        } else {
+            "has no samples"
+        }
+    );
+
+    for name in names {
+        let (_, mut failed, unanswered) = check(&day, &input(day.number, &name), &name, &answers);
+
+        failures.append(&mut failed);
+        for part in unanswered {
+            if !is_left_out(day.number, part, &name) {
+                failures.push(format!(
+                    "day {} part {} has no answer for `{}`",
+                    day.number, part, name
+                ));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Checks `S` against the known answers for its embedded input.
+pub fn check_embedded<S: Solution>()
+where
+    S::Input: 'static,
+{
+    let day = Day::of::<S>();
+    let answers = Answers::parse(answers::KNOWN).unwrap();
+    let (checked, failures, _) = check(&day, day.input, "embedded", &answers);
+
+    assert!(checked > 0, "day {} has no known answers", day.number);
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Generates the tests every day gets from the fixtures: one checking each
+/// sample against its answers, and one checking the embedded input against
+/// the known answers.
+macro_rules! fixture_tests {
+    ($day:ty) => {
+        #[test]
+        fn test_samples() {
+            $crate::fixtures::check_samples::<$day>();
+        }
+
+        #[test]
+        fn test_embedded() {
+            $crate::fixtures::check_embedded::<$day>();
+        }
+    };
+}
+
+pub(crate) use fixture_tests;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_have_samples() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        for (day, part, name, _) in answers.iter() {
+            assert!(
+                samples(day).iter().any(|sample| sample == name),
+                "day {} part {} has an answer for `{}` but no sample",
+                day,
+                part,
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_left_out_parts_have_no_answers() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        for (day, part, name, _) in LEFT_OUT {
+            assert!(samples(*day).iter().any(|sample| sample == name));
+            assert_eq!(
+                answers.get(*day, *part, name),
+                None,
+                "day {} part {} is left out but has an answer for `{}`",
+                day,
+                part,
+                name
+            );
+        }
+    }
+}
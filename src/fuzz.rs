@@ -0,0 +1,241 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::generate::{self, Rng};
+use crate::Day;
+
+/// Pieces of text that mean something to one parser or another, so splicing
+/// them in reaches further into a parser than random characters would.
+const TOKENS: &[&str] = &[
+    "0",
+    "1",
+    "9",
+    "-",
+    "-1",
+    "+",
+    ",",
+    " ",
+    "\n",
+    "\n\n",
+    "[",
+    "]",
+    "[[",
+    "]]",
+    "(",
+    "<",
+    "}",
+    "=",
+    "..",
+    " -> ",
+    "#",
+    ".",
+    "x=",
+    "y=",
+    "F",
+    "FFFFFFFFFFFFFFFFFFFF",
+    "99999999999999999999",
+    "18446744073709551616",
+    "start",
+    "end",
+    "fold along x=",
+    "--- scanner 0 ---",
+    "é",
+];
+
+/// A parser that panicked, and the input it panicked on.
+#[derive(Debug, Clone)]
+pub struct Crash {
+    pub input: String,
+    pub message: String,
+}
+
+/// Inputs to mutate for `day`: its own input, and whatever its generator
+/// makes at a few small sizes.
+pub fn seeds(day: &Day) -> Vec<String> {
+    let mut seeds = vec![String::from(day.input)];
+
+    if let Some(generator) = generate::get(day.number) {
+        for seed in 0..3 {
+            seeds.push(generator.generate(&mut Rng::new(seed), 3));
+        }
+    }
+
+    seeds
+}
+
+/// Changes `input` in a few random places, by cutting, repeating, splicing in
+/// [`TOKENS`] or swapping lines around.
+pub fn mutate(rng: &mut Rng, input: &str) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+
+    for _ in 0..rng.between(1, 4) {
+        let at = rng.below(chars.len() + 1);
+        let end = (at + rng.between(1, 8) as usize).min(chars.len());
+
+        match rng.below(7) {
+            0 => {
+                chars.drain(at..end);
+            }
+            1 => {
+                let token = rng.choose(TOKENS);
+                chars.splice(at..at, token.chars());
+            }
+            2 => {
+                let token = rng.choose(TOKENS);
+                chars.splice(at..end, token.chars());
+            }
+            3 => {
+                let repeated: Vec<_> = chars[at..end].to_vec();
+                chars.splice(at..at, repeated);
+            }
+            4 => chars.truncate(at),
+            5 => {
+                let printable = rng.between(0x20, 0x7e) as u8 as char;
+                chars.insert(at, printable);
+            }
+            _ => {
+                let text: String = chars.iter().collect();
+                let mut lines: Vec<_> = text.split('\n').collect();
+                let (a, b) = (rng.below(lines.len()), rng.below(lines.len()));
+                lines.swap(a, b);
+                chars = lines.join("\n").chars().collect();
+            }
+        }
+    }
+
+    chars.into_iter().collect()
+}
+
+/// Parses `input` as `day`, catching a panic as the message it panicked with.
+pub fn parses(day: &Day, input: &str) -> Result<(), String> {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        // errors are fine, it's panics being looked for
+        let _ = day.parse(input);
+    }))
+    .map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| String::from(*message))
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| String::from("a panic without a message"))
+    })
+}
+
+/// Parses `iterations` mutations of `day`'s [`seeds`], returning the first
+/// one its parser panics on, cut down as far as it will go and still panic.
+///
+/// Panics are caught, but still reported by the panic hook as they happen,
+/// so callers that want quiet should set their own hook first.
+pub fn fuzz(day: &Day, rng: &mut Rng, iterations: usize) -> Option<Crash> {
+    let seeds = seeds(day);
+
+    for _ in 0..iterations {
+        let seed: &String = rng.choose(&seeds);
+        let mut input = mutate(rng, seed);
+        // mutating a mutation now and then reaches further from the seeds
+        while rng.chance(0.3) {
+            input = mutate(rng, &input);
+        }
+
+        if parses(day, &input).is_err() {
+            return Some(minimize(day, input));
+        }
+    }
+
+    None
+}
+
+/// Cuts lines out of `input`, then characters, for as long as the parser
+/// keeps panicking without them.
+fn minimize(day: &Day, mut input: String) -> Crash {
+    for unit in ["\n", ""] {
+        let mut run = input.len();
+
+        while run > 0 {
+            let pieces: Vec<_> = if unit.is_empty() {
+                input.chars().map(String::from).collect()
+            } else {
+                input.split_inclusive('\n').map(String::from).collect()
+            };
+
+            let smaller = (0..pieces.len()).step_by(run).find_map(|start| {
+                let end = (start + run).min(pieces.len());
+                let smaller = [&pieces[..start], &pieces[end..]].concat().concat();
+                parses(day, &smaller).is_err().then_some(smaller)
+            });
+
+            match smaller {
+                Some(smaller) => input = smaller,
+                None => run /= 2,
+            }
+        }
+    }
+
+    let message = parses(day, &input).unwrap_err();
+
+    Crash { input, message }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days, ParseError, Solution};
+
+    struct Fragile;
+
+    impl Solution for Fragile {
+        const DAY: u8 = 0;
+        const INPUT: &'static str = "";
+
+        type Input = ();
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            assert!(!input.contains('!'), "can't take an exclamation");
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_mutate_is_repeatable() {
+        let input = "1,2,3\n4,5,6\n";
+
+        assert_eq!(
+            mutate(&mut Rng::new(1), input),
+            mutate(&mut Rng::new(1), input)
+        );
+        assert!((0..20).any(|seed| mutate(&mut Rng::new(seed), input) != input));
+    }
+
+    #[test]
+    fn test_parses_catches_panics() {
+        let day = Day::of::<Fragile>();
+
+        assert_eq!(parses(&day, "calm"), Ok(()));
+        assert_eq!(
+            parses(&day, "wow!"),
+            Err(String::from("can't take an exclamation"))
+        );
+        assert_eq!(parses(days::get(1).unwrap(), "not a depth\n"), Ok(()));
+    }
+
+    #[test]
+    fn test_minimize() {
+        let crash = minimize(&Day::of::<Fragile>(), String::from("one\ntwo!\nthree\n"));
+
+        assert_eq!(crash.input, "!");
+        assert_eq!(crash.message, "can't take an exclamation");
+    }
+
+    #[test]
+    fn test_parsers_dont_panic() {
+        for day in days::ALL {
+            let crash = fuzz(day, &mut Rng::new(day.number as u64), 100);
+
+            assert!(
+                crash.is_none(),
+                "day {} panicked on {:?}",
+                day.number,
+                crash.unwrap()
+            );
+        }
+    }
+}
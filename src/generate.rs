@@ -0,0 +1,1014 @@
+use std::fmt::Write;
+
+use crate::days::{day11, day24, day25, day9};
+use crate::grid::{Grid, Pos};
+
+/// A small pseudo-random number generator (xorshift64*), so the same seed
+/// always generates the same inputs.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        // splitmix64, which spreads nearby seeds apart and never leaves the
+        // state at zero, where xorshift would get stuck
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        Rng {
+            state: (z ^ (z >> 31)) | 1,
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// A number from 0 up to but not including `n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "there are no numbers below 0");
+
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number from `low` to `high`, both included.
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "{}..={} is empty", low, high);
+
+        low + self.below((high - low) as usize + 1) as i64
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Makes random puzzle inputs for one day, which its solution can parse and
+/// answer. What `size` counts depends on the day, and days whose puzzle fixes
+/// the size of the input ignore it.
+#[derive(Debug, Clone, Copy)]
+pub struct Generator {
+    pub day: u8,
+    /// What `size` counts, for people picking one.
+    pub size_of: &'static str,
+    /// The size of the puzzle's own inputs.
+    pub default_size: usize,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    pub fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (self.generate)(rng, size)
+    }
+}
+
+/// A generator for every day of the calendar so far, in order.
+pub const ALL: &[Generator] = &[
+    Generator {
+        day: 1,
+        size_of: "depths",
+        default_size: 2000,
+        generate: sonar_sweep,
+    },
+    Generator {
+        day: 2,
+        size_of: "movements",
+        default_size: 1000,
+        generate: movements,
+    },
+    Generator {
+        day: 3,
+        size_of: "numbers, at most 4096",
+        default_size: 1000,
+        generate: diagnostic_report,
+    },
+    Generator {
+        day: 4,
+        size_of: "boards",
+        default_size: 100,
+        generate: bingo,
+    },
+    Generator {
+        day: 5,
+        size_of: "lines of vents",
+        default_size: 500,
+        generate: vents,
+    },
+    Generator {
+        day: 6,
+        size_of: "lanternfish",
+        default_size: 300,
+        generate: lanternfish,
+    },
+    Generator {
+        day: 7,
+        size_of: "crabs",
+        default_size: 1000,
+        generate: crabs,
+    },
+    Generator {
+        day: 8,
+        size_of: "displays",
+        default_size: 200,
+        generate: displays,
+    },
+    Generator {
+        day: 9,
+        size_of: "the width and height of the heightmap",
+        default_size: 100,
+        generate: heightmap,
+    },
+    Generator {
+        day: 10,
+        size_of: "lines",
+        default_size: 100,
+        generate: navigation,
+    },
+    Generator {
+        day: 11,
+        size_of: "nothing, the grid is always 10x10",
+        default_size: 10,
+        generate: octopuses,
+    },
+    Generator {
+        day: 12,
+        size_of: "small caves, from 2 to 12",
+        default_size: 6,
+        generate: caves,
+    },
+    Generator {
+        day: 13,
+        size_of: "folds, at most 12",
+        default_size: 12,
+        generate: transparent_paper,
+    },
+    Generator {
+        day: 14,
+        size_of: "elements in the template",
+        default_size: 20,
+        generate: polymer,
+    },
+    Generator {
+        day: 15,
+        size_of: "the width and height of the cave",
+        default_size: 100,
+        generate: chitons,
+    },
+    Generator {
+        day: 16,
+        size_of: "packets",
+        default_size: 250,
+        generate: transmission,
+    },
+    Generator {
+        day: 17,
+        size_of: "nothing, there's only ever one target",
+        default_size: 1,
+        generate: target_area,
+    },
+    Generator {
+        day: 18,
+        size_of: "snailfish numbers",
+        default_size: 100,
+        generate: snailfish,
+    },
+    Generator {
+        day: 19,
+        size_of: "scanners, at most 40",
+        default_size: 30,
+        generate: scanners,
+    },
+    Generator {
+        day: 20,
+        size_of: "the width and height of the image",
+        default_size: 100,
+        generate: trench_map,
+    },
+    Generator {
+        day: 21,
+        size_of: "nothing, there are always two players",
+        default_size: 2,
+        generate: dirac_dice,
+    },
+    Generator {
+        day: 22,
+        size_of: "reboot steps",
+        default_size: 420,
+        generate: reactor_reboot,
+    },
+    Generator {
+        day: 23,
+        size_of: "nothing, the burrow is always two deep",
+        default_size: 2,
+        generate: amphipods,
+    },
+    Generator {
+        day: 24,
+        size_of: "nothing, MONAD always checks 14 digits",
+        default_size: 14,
+        generate: monad,
+    },
+    Generator {
+        day: 25,
+        size_of: "the width and height of the sea floor",
+        default_size: 139,
+        generate: sea_cucumbers,
+    },
+];
+
+pub fn get(day: u8) -> Option<&'static Generator> {
+    ALL.iter().find(|generator| generator.day == day)
+}
+
+/// One line per item, each ending in a newline like the puzzle inputs do.
+fn lines(items: impl IntoIterator<Item = String>) -> String {
+    items.into_iter().fold(String::new(), |mut text, line| {
+        text += &line;
+        text.push('\n');
+        text
+    })
+}
+
+fn joined<T: ToString>(items: &[T], separator: &str) -> String {
+    items
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Depths that mostly get deeper.
+fn sonar_sweep(rng: &mut Rng, size: usize) -> String {
+    let mut depth = rng.between(100, 200);
+
+    lines((0..size.max(1)).map(|_| {
+        depth = (depth + rng.between(-10, 20)).max(0);
+        depth.to_string()
+    }))
+}
+
+/// Movements steered so that the position, and both answers, always fit in
+/// the solver's `i32`s.
+fn movements(rng: &mut Rng, size: usize) -> String {
+    let fits = |value: i64| i32::try_from(value).is_ok();
+    let (mut horizontal, mut aim, mut depth) = (0i64, 0i64, 0i64);
+    let mut movements = Vec::new();
+
+    while movements.len() < size.max(1) {
+        let amount = rng.between(1, 9);
+        let (direction, next) = match rng.below(3) {
+            0 => ("forward", (horizontal + amount, aim, depth + aim * amount)),
+            1 => ("up", (horizontal, aim - amount, depth)),
+            _ => ("down", (horizontal, aim + amount, depth)),
+        };
+
+        if fits(next.0 * next.1) && fits(next.0 * next.2) {
+            (horizontal, aim, depth) = next;
+            movements.push(format!("{} {}", direction, amount));
+        }
+    }
+
+    lines(movements)
+}
+
+/// Distinct 12 bit numbers.
+fn diagnostic_report(rng: &mut Rng, size: usize) -> String {
+    let mut numbers: Vec<u16> = (0..4096).collect();
+    rng.shuffle(&mut numbers);
+
+    lines(
+        numbers[..size.clamp(1, 4096)]
+            .iter()
+            .map(|n| format!("{:012b}", n)),
+    )
+}
+
+/// Boards drawn from the numbers 0 to 99, every one of which gets called, so
+/// every board wins in the end.
+fn bingo(rng: &mut Rng, size: usize) -> String {
+    let mut called: Vec<u32> = (0..100).collect();
+    rng.shuffle(&mut called);
+    let mut text = joined(&called, ",") + "\n";
+
+    for _ in 0..size.max(1) {
+        let mut board: Vec<u32> = (0..100).collect();
+        rng.shuffle(&mut board);
+
+        text.push('\n');
+        for row in board[..25].chunks(5) {
+            let row: Vec<_> = row.iter().map(|n| format!("{:2}", n)).collect();
+            writeln!(text, "{}", row.join(" ")).unwrap();
+        }
+    }
+
+    text
+}
+
+/// Horizontal, vertical and 45 degree lines on a 1000x1000 floor.
+fn vents(rng: &mut Rng, size: usize) -> String {
+    const DIRECTIONS: [(i64, i64); 8] = [
+        (1, 0),
+        (-1, 0),
+        (0, 1),
+        (0, -1),
+        (1, 1),
+        (1, -1),
+        (-1, 1),
+        (-1, -1),
+    ];
+    // how far a line can go from `at` in `direction` before leaving the floor
+    let reach = |at: i64, direction: i64| match direction {
+        1 => 999 - at,
+        -1 => at,
+        _ => i64::MAX,
+    };
+
+    lines((0..size.max(1)).map(|_| {
+        let (x, y) = (rng.between(0, 999), rng.between(0, 999));
+        let directions: Vec<_> = DIRECTIONS
+            .iter()
+            .map(|(dx, dy)| (*dx, *dy, reach(x, *dx).min(reach(y, *dy))))
+            .filter(|(_, _, reach)| *reach > 0)
+            .collect();
+        let (dx, dy, reach) = *rng.choose(&directions);
+        let length = rng.between(1, reach.min(300));
+
+        format!("{},{} -> {},{}", x, y, x + dx * length, y + dy * length)
+    }))
+}
+
+/// Fish with 1 to 5 days left on their timers, as in the puzzle.
+fn lanternfish(rng: &mut Rng, size: usize) -> String {
+    let timers: Vec<_> = (0..size.max(1)).map(|_| rng.between(1, 5)).collect();
+
+    joined(&timers, ",") + "\n"
+}
+
+/// Crab positions, spread no wider than keeps the fuel the solver adds up for
+/// any position within an `i32`. There are always at least two positions.
+fn crabs(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let triangular = |n: i64| n * (n + 1) / 2;
+    let spread = (1..=2000)
+        .rev()
+        .find(|spread| size as i64 * triangular(*spread) <= i32::MAX as i64)
+        .unwrap_or(1);
+
+    let mut positions: Vec<_> = (0..size).map(|_| rng.between(0, spread)).collect();
+    positions[0] = 0;
+    positions[1] = spread;
+    rng.shuffle(&mut positions);
+
+    joined(&positions, ",") + "\n"
+}
+
+/// Displays with their wires crossed at random, each showing all ten digits
+/// and then four digits of output.
+fn displays(rng: &mut Rng, size: usize) -> String {
+    const DIGITS: [&str; 10] = [
+        "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+    ];
+
+    fn render(rng: &mut Rng, wires: &[char], digit: usize) -> String {
+        let mut segments: Vec<char> = DIGITS[digit]
+            .chars()
+            .map(|segment| wires[segment as usize - 'a' as usize])
+            .collect();
+        rng.shuffle(&mut segments);
+
+        String::from_iter(segments)
+    }
+
+    lines((0..size.max(1)).map(|_| {
+        let mut wires: Vec<char> = "abcdefg".chars().collect();
+        rng.shuffle(&mut wires);
+        let mut digits: Vec<usize> = (0..10).collect();
+        rng.shuffle(&mut digits);
+
+        let patterns: Vec<_> = digits
+            .iter()
+            .map(|digit| render(rng, &wires, *digit))
+            .collect();
+        let output: Vec<_> = (0..4)
+            .map(|_| {
+                let digit = rng.below(10);
+                render(rng, &wires, digit)
+            })
+            .collect();
+
+        format!("{} | {}", patterns.join(" "), output.join(" "))
+    }))
+}
+
+/// A heightmap, drawn again until it has the three basins whose sizes part
+/// two multiplies together, which the parser insists on.
+fn heightmap(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+
+    loop {
+        let heights = Grid::from_fn(size, size, |_| {
+            if rng.chance(0.25) {
+                9
+            } else {
+                rng.below(9) as u32
+            }
+        });
+
+        if day9::basin_sizes(&heights).len() >= 3 {
+            return heights.to_string() + "\n";
+        }
+    }
+}
+
+/// Lines of chunks, each either corrupted by a wrong closing bracket or cut
+/// off before it's complete. The first line is always incomplete, as the
+/// solver needs one to score, and chunks nest no deeper than keeps the
+/// completion scores within a `u64`.
+fn navigation(rng: &mut Rng, size: usize) -> String {
+    const OPEN: [char; 4] = ['(', '[', '{', '<'];
+    const CLOSE: [char; 4] = [')', ']', '}', '>'];
+
+    lines((0..size.max(1)).map(|line| {
+        // a complete line, as the kind of each bracket and whether it opens
+        let mut brackets = Vec::new();
+        let mut open = Vec::new();
+        while brackets.len() < 10 || !open.is_empty() {
+            let may_open = open.len() < 20 && brackets.len() < 100;
+            if open.is_empty() || (may_open && rng.chance(0.55)) {
+                let kind = rng.below(4);
+                open.push(kind);
+                brackets.push((kind, true));
+            } else {
+                brackets.push((open.pop().unwrap(), false));
+            }
+        }
+
+        if line == 0 || rng.chance(0.5) {
+            // stop somewhere with chunks still open
+            let stops: Vec<_> = (1..brackets.len())
+                .filter(|end| {
+                    let opened = brackets[..*end].iter().filter(|(_, opens)| *opens).count();
+                    opened * 2 > *end
+                })
+                .collect();
+            brackets.truncate(*rng.choose(&stops));
+        } else {
+            let closes: Vec<_> = (0..brackets.len()).filter(|i| !brackets[*i].1).collect();
+            let i = *rng.choose(&closes);
+            brackets[i].0 = (brackets[i].0 + rng.between(1, 3) as usize) % 4;
+        }
+
+        brackets
+            .iter()
+            .map(|(kind, opens)| if *opens { OPEN[*kind] } else { CLOSE[*kind] })
+            .collect()
+    }))
+}
+
+/// Energy levels for the puzzle's 10x10 grid of octopuses. Not every grid
+/// ever flashes all at once, and the solver would look for that step forever,
+/// so grids are drawn again until one does within a thousand steps.
+fn octopuses(rng: &mut Rng, _size: usize) -> String {
+    loop {
+        let octopi = Grid::from_fn(10, 10, |_| rng.below(10) as u32);
+
+        let mut stepped = octopi.clone();
+        if (0..1000).any(|_| day11::perform_step(&mut stepped) == 100) {
+            return octopi.to_string() + "\n";
+        }
+    }
+}
+
+/// A cave system with two letter cave names. Big caves are never joined to
+/// each other, or a path could bounce between them forever, and there are
+/// few enough small caves that the number of paths stays countable.
+fn caves(rng: &mut Rng, size: usize) -> String {
+    let small_caves = size.clamp(2, 12);
+    let big_caves = small_caves / 3 + 1;
+
+    let mut names = vec![String::from("start"), String::from("end")];
+    while names.len() < 2 + small_caves + big_caves {
+        let is_big = names.len() >= 2 + small_caves;
+        let name: String = (0..2)
+            .map(|_| {
+                let letter = (b'a' + rng.below(26) as u8) as char;
+                if is_big {
+                    letter.to_ascii_uppercase()
+                } else {
+                    letter
+                }
+            })
+            .collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let is_big = |cave: usize| cave >= 2 + small_caves;
+    let mut edges = Vec::new();
+    for a in 0..names.len() {
+        for b in (a + 1)..names.len() {
+            let chance = match (a, b) {
+                (0, 1) => 0.0,
+                (0 | 1, _) => 0.3,
+                _ if is_big(a) && is_big(b) => 0.0,
+                _ if is_big(a) || is_big(b) => 0.4,
+                _ => 0.2,
+            };
+            if rng.chance(chance) {
+                edges.push((a, b));
+            }
+        }
+    }
+    // start and end always lead somewhere
+    for cave in [0, 1] {
+        if edges.iter().all(|(a, b)| *a != cave && *b != cave) {
+            edges.push((cave, 2 + rng.below(small_caves + big_caves)));
+        }
+    }
+    rng.shuffle(&mut edges);
+
+    lines(edges.into_iter().map(|(a, b)| {
+        if rng.chance(0.5) {
+            format!("{}-{}", names[a], names[b])
+        } else {
+            format!("{}-{}", names[b], names[a])
+        }
+    }))
+}
+
+/// Dots that spell out random pixels once the paper is folded. The paper is
+/// unfolded from eight 4x6 letters, one random fold at a time, with each dot
+/// landing on one side of the fold or both and never on the fold itself.
+fn transparent_paper(rng: &mut Rng, size: usize) -> String {
+    let mut width = 39;
+    let mut height = 6;
+    let mut dots: Vec<(usize, usize)> = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .filter(|(x, _)| x % 5 != 4)
+        .filter(|_| rng.chance(0.5))
+        .collect();
+    let mut folds = Vec::new();
+
+    for _ in 0..size.clamp(1, 12) {
+        let along_x = rng.chance(0.5);
+        let axis = if along_x { width } else { height };
+
+        dots = dots
+            .into_iter()
+            .flat_map(|(x, y)| {
+                let at = if along_x { x } else { y };
+                let mirrored = 2 * axis - at;
+                let mut landed = Vec::new();
+                match rng.below(3) {
+                    0 => landed.push(at),
+                    1 => landed.push(mirrored),
+                    _ => landed.extend([at, mirrored]),
+                }
+                landed
+                    .into_iter()
+                    .map(move |at| if along_x { (at, y) } else { (x, at) })
+            })
+            .collect();
+
+        if along_x {
+            width = 2 * width + 1;
+        } else {
+            height = 2 * height + 1;
+        }
+        folds.push(format!(
+            "fold along {}={}",
+            if along_x { "x" } else { "y" },
+            axis
+        ));
+    }
+    rng.shuffle(&mut dots);
+    folds.reverse();
+
+    lines(dots.into_iter().map(|(x, y)| format!("{},{}", x, y))) + "\n" + &lines(folds)
+}
+
+/// A template of random elements, with an insertion rule for every pair of
+/// elements that could come up.
+fn polymer(rng: &mut Rng, size: usize) -> String {
+    let mut letters: Vec<char> = ('A'..='Z').collect();
+    rng.shuffle(&mut letters);
+    let elements = &letters[..rng.between(4, 10) as usize];
+
+    let template: String = (0..size.max(2)).map(|_| *rng.choose(elements)).collect();
+    let rules = elements
+        .iter()
+        .flat_map(|a| elements.iter().map(move |b| (*a, *b)));
+    let rules: Vec<_> = rules
+        .map(|(a, b)| format!("{}{} -> {}", a, b, rng.choose(elements)))
+        .collect();
+
+    template + "\n\n" + &lines(rules)
+}
+
+/// A cave of risk levels from 1 to 9.
+fn chitons(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+
+    Grid::from_fn(size, size, |_| rng.between(1, 9)).to_string() + "\n"
+}
+
+/// A transmission in hex, made up of roughly `size` packets. Operators that
+/// would overflow the solver's `u64` while adding or multiplying become
+/// maximums instead.
+fn transmission(rng: &mut Rng, size: usize) -> String {
+    let mut budget = size.max(1);
+    let mut bits = Vec::new();
+    packet(rng, &mut budget, 0, &mut bits);
+
+    while bits.len() % 4 != 0 {
+        bits.push(false);
+    }
+    let hex: String = bits
+        .chunks(4)
+        .map(|nibble| {
+            let value = nibble.iter().fold(0, |value, bit| value << 1 | *bit as u32);
+            char::from_digit(value, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect();
+
+    hex + "\n"
+}
+
+fn push_bits(bits: &mut Vec<bool>, value: u64, width: usize) {
+    bits.extend((0..width).rev().map(|i| value >> i & 1 == 1));
+}
+
+/// Appends a packet to `bits`, returning what it evaluates to.
+fn packet(rng: &mut Rng, budget: &mut usize, depth: usize, bits: &mut Vec<bool>) -> u64 {
+    *budget = budget.saturating_sub(1);
+    push_bits(bits, rng.below(8) as u64, 3);
+
+    if *budget == 0 || depth >= 12 || rng.chance(0.4) {
+        let value = rng.next_u64() >> rng.between(28, 63);
+        let nibbles = (64 - value.leading_zeros() as usize).div_ceil(4).max(1);
+
+        push_bits(bits, 4, 3);
+        for i in (0..nibbles).rev() {
+            bits.push(i > 0);
+            push_bits(bits, value >> (i * 4) & 0xF, 4);
+        }
+        return value;
+    }
+
+    let kind = *rng.choose(&[0, 1, 2, 3, 5, 6, 7]);
+    let count = if kind >= 5 {
+        2
+    } else {
+        rng.between(1, 4.min(*budget as i64).max(1)) as usize
+    };
+
+    let mut children = Vec::new();
+    let mut values = Vec::new();
+    for _ in 0..count {
+        let mut child = Vec::new();
+        values.push(packet(rng, budget, depth + 1, &mut child));
+        children.extend(child);
+    }
+
+    let (kind, value) = match kind {
+        0 => values
+            .iter()
+            .try_fold(0u64, |sum, value| sum.checked_add(*value))
+            .map_or((3, None), |sum| (0, Some(sum))),
+        1 => values
+            .iter()
+            .try_fold(1u64, |product, value| product.checked_mul(*value))
+            .map_or((3, None), |product| (1, Some(product))),
+        2 => (2, values.iter().min().copied()),
+        5 => (5, Some((values[0] > values[1]) as u64)),
+        6 => (6, Some((values[0] < values[1]) as u64)),
+        7 => (7, Some((values[0] == values[1]) as u64)),
+        _ => (3, None),
+    };
+    let value = value.unwrap_or_else(|| *values.iter().max().unwrap());
+
+    push_bits(bits, kind, 3);
+    if children.len() < 1 << 15 && rng.chance(0.5) {
+        bits.push(false);
+        push_bits(bits, children.len() as u64, 15);
+    } else {
+        bits.push(true);
+        push_bits(bits, count as u64, 11);
+    }
+    bits.extend(children);
+
+    value
+}
+
+/// A target below and to the right of the probe, placed so some starting
+/// speed leaves the probe dropping straight down inside it.
+fn target_area(rng: &mut Rng, _size: usize) -> String {
+    let speed = rng.between(5, 25);
+    let stops_at = speed * (speed + 1) / 2;
+    let (left, right) = (stops_at - rng.between(0, 10), stops_at + rng.between(0, 30));
+    let bottom = -rng.between(5, 150);
+    let top = bottom + rng.between(1, -bottom - 1);
+
+    format!(
+        "target area: x={}..{}, y={}..{}\n",
+        left, right, bottom, top
+    )
+}
+
+/// Reduced snailfish numbers, with pairs nested at most four deep and no
+/// number above 9.
+fn snailfish(rng: &mut Rng, size: usize) -> String {
+    fn pair(rng: &mut Rng, depth: usize) -> String {
+        format!("[{},{}]", element(rng, depth), element(rng, depth))
+    }
+
+    fn element(rng: &mut Rng, depth: usize) -> String {
+        if depth == 4 || rng.chance(depth as f64 / 5.0) {
+            rng.below(10).to_string()
+        } else {
+            pair(rng, depth + 1)
+        }
+    }
+
+    lines((0..size.max(2)).map(|_| pair(rng, 1)))
+}
+
+/// Scanners in a chain, each turned a random way round and each seeing at
+/// least twelve of the beacons the one before it sees, as the puzzle promises.
+fn scanners(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 40);
+    let rotations = rotations();
+    let mut positions = vec![[0i64; 3]];
+    let mut beacons: Vec<[i64; 3]> = Vec::new();
+
+    let mut around = |rng: &mut Rng, low: [i64; 3], high: [i64; 3]| {
+        let beacon = [0, 1, 2].map(|axis| rng.between(low[axis], high[axis]));
+        if !beacons.contains(&beacon) {
+            beacons.push(beacon);
+        }
+    };
+
+    for i in 0..count {
+        let at = positions[i];
+        for _ in 0..rng.between(5, 15) {
+            around(rng, at.map(|c| c - 1000), at.map(|c| c + 1000));
+        }
+
+        if i + 1 < count {
+            let next = at.map(|c| c + rng.between(-1100, 1100));
+            // the box both scanners can see into
+            let low = [0, 1, 2].map(|axis| at[axis].max(next[axis]) - 1000);
+            let high = [0, 1, 2].map(|axis| at[axis].min(next[axis]) + 1000);
+            for _ in 0..12 {
+                around(rng, low, high);
+            }
+            positions.push(next);
+        }
+    }
+
+    let mut text = String::new();
+    for (i, at) in positions.iter().enumerate() {
+        let rotation = rng.choose(&rotations);
+        let mut seen: Vec<_> = beacons
+            .iter()
+            .map(|beacon| [0, 1, 2].map(|axis| beacon[axis] - at[axis]))
+            .filter(|offset| offset.iter().all(|c| c.abs() <= 1000))
+            .map(|offset| rotation.map(|(axis, sign)| offset[axis] * sign))
+            .collect();
+        rng.shuffle(&mut seen);
+
+        if i > 0 {
+            text.push('\n');
+        }
+        writeln!(text, "--- scanner {} ---", i).unwrap();
+        for [x, y, z] in seen {
+            writeln!(text, "{},{},{}", x, y, z).unwrap();
+        }
+    }
+
+    text
+}
+
+/// The 24 ways a scanner can face, as the axis and sign each of its own axes
+/// takes from the world's.
+fn rotations() -> Vec<[(usize, i64); 3]> {
+    let permutations = [
+        ([0, 1, 2], 1),
+        ([1, 2, 0], 1),
+        ([2, 0, 1], 1),
+        ([0, 2, 1], -1),
+        ([2, 1, 0], -1),
+        ([1, 0, 2], -1),
+    ];
+    let mut rotations = Vec::new();
+
+    for (axes, parity) in permutations {
+        for signs in 0..8 {
+            let signs = [0, 1, 2].map(|i| if signs >> i & 1 == 1 { -1 } else { 1 });
+            // mirror images aren't rotations
+            if parity * signs.iter().product::<i64>() == 1 {
+                rotations.push([0, 1, 2].map(|i| (axes[i], signs[i])));
+            }
+        }
+    }
+
+    rotations
+}
+
+/// A random enhancement algorithm and a random image to enhance.
+fn trench_map(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut pixel = |_| if rng.chance(0.5) { '#' } else { '.' };
+    let algorithm: String = (0..512).map(&mut pixel).collect();
+    let image = Grid::from_fn(size, size, |pos: Pos| pixel(pos.x));
+
+    algorithm + "\n\n" + &image.to_string() + "\n"
+}
+
+/// Starting positions for the two players.
+fn dirac_dice(rng: &mut Rng, _size: usize) -> String {
+    format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}\n",
+        rng.between(1, 10),
+        rng.between(1, 10)
+    )
+}
+
+/// Reboot steps over a score of small cuboids inside the
+/// initialization region, then big ones far out of it, like the
+/// puzzle's own.
+fn reactor_reboot(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|i| {
+        let (reach, longest) = if i < 20 { (50, 50) } else { (100_000, 50_000) };
+        let mut range = |axis| {
+            let low = rng.between(-reach, reach - 1);
+            let high = (low + rng.between(0, longest)).min(reach);
+            format!("{}={}..{}", axis, low, high)
+        };
+        let (x, y, z) = (range("x"), range("y"), range("z"));
+        let state = if i == 0 || rng.chance(0.6) {
+            "on"
+        } else {
+            "off"
+        };
+
+        format!("{} {},{},{}", state, x, y, z)
+    }))
+}
+
+/// Two of each amphipod shuffled into the rooms, with the hallway empty.
+/// About one in eight of them get stuck once the burrow is unfolded, which
+/// part two answers with `stuck`.
+fn amphipods(rng: &mut Rng, _size: usize) -> String {
+    let mut amphipods = ['A', 'A', 'B', 'B', 'C', 'C', 'D', 'D'];
+    rng.shuffle(&mut amphipods);
+    let row = |row: &[char]| joined(row, "#");
+
+    format!(
+        "#############\n#...........#\n###{}###\n  #{}#\n  #########\n",
+        row(&amphipods[..4]),
+        row(&amphipods[4..])
+    )
+}
+
+/// A MONAD whose blocks push and pop in a random but balanced order, with
+/// every pair of digits at most 8 apart so some model number gets through.
+fn monad(rng: &mut Rng, _size: usize) -> String {
+    let (mut pushes, mut stack) = (0, Vec::new());
+    let mut blocks = Vec::new();
+
+    for _ in 0..14 {
+        let push = pushes < 7 && (stack.is_empty() || rng.chance(0.5));
+        let (div, check, offset) = if push {
+            pushes += 1;
+            let offset = rng.between(0, 16);
+            stack.push(offset);
+            (1, rng.between(10, 16), offset)
+        } else {
+            let pushed = stack.pop().unwrap();
+            (26, rng.between(-8, 8) - pushed, rng.between(0, 16))
+        };
+
+        blocks.extend(day24::TEMPLATE.iter().map(|line| {
+            line.replace("{1 or 26}", &div.to_string())
+                .replace("{check}", &check.to_string())
+                .replace("{offset}", &offset.to_string())
+        }));
+    }
+
+    lines(blocks)
+}
+
+/// A sea floor with about a quarter of it facing east and a quarter facing
+/// south. Some herds never stop, like a row of east-facing ones with nothing
+/// crossing it, which part one answers with `never stops`.
+fn sea_cucumbers(rng: &mut Rng, size: usize) -> String {
+    let spots = Grid::from_fn(size, size, |_| match rng.below(4) {
+        0 => day25::Spot::East,
+        1 => day25::Spot::South,
+        _ => day25::Spot::Empty,
+    });
+
+    spots.to_string() + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days, Part};
+
+    #[test]
+    fn test_rng() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+
+        let mut rng = Rng::new(0);
+        let rolls: Vec<_> = (0..1000).map(|_| rng.between(1, 6)).collect();
+        for face in 1..=6 {
+            assert!(rolls.contains(&face));
+        }
+        assert!(rolls.iter().all(|roll| (1..=6).contains(roll)));
+    }
+
+    #[test]
+    fn test_rotations() {
+        let rotations = rotations();
+
+        assert_eq!(rotations.len(), 24);
+        for (i, rotation) in rotations.iter().enumerate() {
+            assert!(!rotations[..i].contains(rotation));
+        }
+    }
+
+    #[test]
+    fn test_every_day_has_a_generator() {
+        for day in days::ALL {
+            assert!(
+                get(day.number).is_some(),
+                "day {} has no generator",
+                day.number
+            );
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for generator in ALL {
+            let day = days::get(generator.day).unwrap();
+
+            for seed in 0..5 {
+                for size in [1, 5, 20] {
+                    let input = generator.generate(&mut Rng::new(seed), size);
+                    let parsed = day.parse(&input).unwrap_or_else(|err| {
+                        panic!(
+                            "day {} couldn't parse seed {} at size {}\n{}",
+                            day.number,
+                            seed,
+                            size,
+                            err.annotate(&input)
+                        )
+                    });
+
+                    for part in Part::BOTH {
+                        day.solve(part, &parsed);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generators_are_repeatable() {
+        for generator in ALL {
+            assert_eq!(
+                generator.generate(&mut Rng::new(3), 10),
+                generator.generate(&mut Rng::new(3), 10),
+                "day {} generated different inputs from the same seed",
+                generator.day
+            );
+        }
+    }
+}
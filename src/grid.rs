@@ -0,0 +1,330 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::ParseError;
+
+/// A cell of a [`Grid`], counted from the top left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Pos {
+    pub const fn new(x: usize, y: usize) -> Pos {
+        Pos { x, y }
+    }
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Up, down, left and right.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+/// The orthogonal steps followed by the diagonal ones.
+const ALL_AROUND: [(isize, isize); 8] = [
+    (0, -1),
+    (0, 1),
+    (-1, 0),
+    (1, 0),
+    (-1, -1),
+    (1, -1),
+    (-1, 1),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid by calling `f` for every position, row by row.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Pos::new(x, y)))
+            .map(&mut f)
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid out of rows, or `None` if they aren't all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses the lines of `text`, a slice of `input`, one cell per
+    /// character. Blank lines are skipped and every row must be as wide as
+    /// the first.
+    pub fn parse(
+        input: &str,
+        text: &str,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut rows = Vec::new();
+
+        for line in text.lines().filter(|line| !line.is_empty()) {
+            let row = line
+                .char_indices()
+                .map(|(i, c)| cell(c).ok_or_else(|| ParseError::at(input, &line[i..], expected)))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if let Some(first) = rows.first().map(Vec::len) {
+                if row.len() != first {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("a row {} cells wide", first),
+                    ));
+                }
+            }
+            rows.push(row);
+        }
+
+        if rows.is_empty() {
+            return Err(ParseError::at(input, text, "a row of cells"));
+        }
+
+        Ok(Grid::from_rows(rows).expect("rows were checked to be the same width"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.y * self.width + pos.x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.y * self.width + pos.x])
+        } else {
+            None
+        }
+    }
+
+    /// The position `dx` across and `dy` down from `pos`, if it's on the grid.
+    pub fn step(&self, pos: Pos, dx: isize, dy: isize) -> Option<Pos> {
+        let pos = Pos::new(pos.x.checked_add_signed(dx)?, pos.y.checked_add_signed(dy)?);
+
+        if self.contains(pos) {
+            Some(pos)
+        } else {
+            None
+        }
+    }
+
+    /// The positions up, down, left and right of `pos` that are on the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |(dx, dy)| self.step(pos, *dx, *dy))
+    }
+
+    /// The positions around `pos`, diagonals included, that are on the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ALL_AROUND
+            .iter()
+            .filter_map(move |(dx, dy)| self.step(pos, *dx, *dy))
+    }
+
+    /// Every position on the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Pos::new(x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn cells_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is off the grid", x);
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<u32> {
+    /// Parses a map of single digits.
+    pub fn digits(input: &str, text: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, text, "a digit", |c| c.to_digit(10))
+    }
+}
+
+impl Grid<bool> {
+    /// Parses a map of `#` and `.`, with `#` as `true`.
+    pub fn pixels(input: &str, text: &str) -> Result<Grid<bool>, ParseError> {
+        Grid::parse(input, text, "`#` or `.`", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{} is off the {}x{} grid", pos, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{} is off the {}x{} grid", pos, width, height))
+    }
+}
+
+/// Renders the grid row by row, each cell written with its own `Display`.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_digits() {
+        let input = "
+123
+456
+";
+        let grid = Grid::digits(input, input).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Pos::new(2, 1)], 6);
+        assert_eq!(grid.get(Pos::new(3, 1)), None);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "123\n4x6\n";
+        let err = Grid::digits(input, input).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+
+        let input = "#.#\n##\n";
+        let err = Grid::pixels(input, input).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "a row 3 cells wide");
+
+        assert!(Grid::pixels("\n", "\n").is_err());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(10, 10, 0);
+
+        assert_eq!(grid.neighbours4(Pos::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours4(Pos::new(4, 8)).count(), 4);
+        assert_eq!(grid.neighbours8(Pos::new(9, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Pos::new(4, 8)).count(), 8);
+        assert_eq!(grid.neighbours8(Pos::new(8, 4)).count(), 8);
+    }
+
+    #[test]
+    fn test_from_rows() {
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+
+        let grid = Grid::from_fn(2, 2, |pos| pos.x + pos.y * 2);
+        assert_eq!(Grid::from_rows(vec![vec![0, 1], vec![2, 3]]), Some(grid));
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+use crate::{Day, ParseError};
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The input checked in alongside the day's solution.
+    Embedded,
+    Stdin,
+    File(PathBuf),
+    /// A directory holding one `dayN.txt` file per day.
+    Dir(PathBuf),
+}
+
+impl Source {
+    /// Interprets a command line argument: `-` is stdin, a directory is
+    /// searched for `dayN.txt` files and anything else is a single file.
+    pub fn from_arg(arg: &str) -> Source {
+        let path = PathBuf::from(arg);
+
+        if arg == "-" {
+            Source::Stdin
+        } else if path.is_dir() {
+            Source::Dir(path)
+        } else {
+            Source::File(path)
+        }
+    }
+
+    /// Whether the source can provide inputs for more than one day.
+    pub fn covers_many_days(&self) -> bool {
+        matches!(self, Source::Embedded | Source::Dir(_))
+    }
+
+    /// The name answers for this source are filed under: `embedded` for the
+    /// checked in inputs, otherwise the name of the directory or the file's
+    /// stem, so that several people's inputs can sit side by side.
+    pub fn name(&self) -> String {
+        match self {
+            Source::Embedded => String::from("embedded"),
+            Source::Stdin => String::from("stdin"),
+            Source::File(path) => path.file_stem().map_or_else(
+                || path.display().to_string(),
+                |stem| stem.to_string_lossy().into_owned(),
+            ),
+            Source::Dir(path) => {
+                let path = path.canonicalize().unwrap_or_else(|_| path.clone());
+                path.file_name().map_or_else(
+                    || path.display().to_string(),
+                    |name| name.to_string_lossy().into_owned(),
+                )
+            }
+        }
+    }
+
+    pub fn read(&self, day: &Day) -> io::Result<String> {
+        match self {
+            Source::Embedded => Ok(String::from(day.input)),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::File(path) => fs::read_to_string(path),
+            Source::Dir(dir) => fs::read_to_string(dir.join(format!("day{}.txt", day.number))),
+        }
+    }
+
+    /// Reads `day`'s input and hands it to `parse`, describing what went
+    /// wrong at either step, with parse errors annotated under the line they
+    /// point at.
+    pub fn load<T>(
+        &self,
+        day: &Day,
+        parse: impl FnOnce(&str) -> Result<T, ParseError>,
+    ) -> Result<T, String> {
+        let input = self
+            .read(day)
+            .map_err(|err| format!("couldn't read day {} from {}: {}", day.number, self, err))?;
+
+        parse(&input).map_err(|err| {
+            format!(
+                "couldn't parse day {} from {}\n{}",
+                day.number,
+                self,
+                err.annotate(&input)
+            )
+        })
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Embedded => write!(f, "the embedded input"),
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) | Source::Dir(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg("day1.txt"),
+            Source::File(PathBuf::from("day1.txt"))
+        );
+        assert_eq!(
+            Source::from_arg(env!("CARGO_MANIFEST_DIR")),
+            Source::Dir(PathBuf::from(env!("CARGO_MANIFEST_DIR")))
+        );
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!(Source::Embedded.name(), "embedded");
+        assert_eq!(
+            Source::File(PathBuf::from("inputs/alice.txt")).name(),
+            "alice"
+        );
+        assert_eq!(Source::Dir(PathBuf::from("inputs/bob/")).name(), "bob");
+    }
+
+    #[test]
+    fn test_load() {
+        let day = days::get(1).unwrap();
+        let source = Source::File(PathBuf::from("no/such/day1.txt"));
+
+        assert!(source
+            .load(day, |_| Ok(()))
+            .unwrap_err()
+            .starts_with("couldn't read day 1 from no/such/day1.txt: "));
+
+        let err = Source::Embedded
+            .load(day, |input| -> Result<(), _> {
+                Err(ParseError::at(input, &input[..3], "a depth"))
+            })
+            .unwrap_err();
+
+        assert!(err.starts_with("couldn't parse day 1 from the embedded input\n"));
+        assert!(err.ends_with("^ expected a depth"));
+    }
+
+    #[test]
+    fn test_read_dir() {
+        let day = days::get(1).unwrap();
+        let dir = Source::Dir(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/days"));
+
+        assert_eq!(dir.read(day).unwrap(), Source::Embedded.read(day).unwrap());
+    }
+}
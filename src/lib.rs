@@ -0,0 +1,64 @@
+// Most puzzles walk grids and lists by index, which reads closer to the
+// puzzle text than zipped iterators do.
+#![allow(clippy::needless_range_loop)]
+
+use std::fmt;
+
+pub mod animate;
+pub mod answers;
+pub mod bench;
+pub mod check;
+mod day;
+pub mod days;
+pub mod export;
+#[cfg(test)]
+mod fixtures;
+pub mod fuzz;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod pool;
+#[cfg(test)]
+mod prop;
+pub mod repl;
+pub mod report;
+pub mod search;
+pub mod trace;
+
+pub use day::{Answer, Day, Part, Parsed};
+pub use parse::ParseError;
+
+/// A single day's puzzle: how to parse the input and how to answer each part.
+///
+/// Parts that haven't been solved yet can be left out, in which case they
+/// answer with [`Unsolved`].
+pub trait Solution {
+    /// The day of the calendar this solution is for.
+    const DAY: u8;
+
+    /// The puzzle input checked in alongside the solution.
+    const INPUT: &'static str;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(_input: &Self::Input) -> impl fmt::Display {
+        Unsolved
+    }
+
+    fn part_two(_input: &Self::Input) -> impl fmt::Display {
+        Unsolved
+    }
+}
+
+/// The answer given by a part that hasn't been solved yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl fmt::Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unsolved")
+    }
+}
@@ -0,0 +1,140 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A problem found while parsing a puzzle input, and where in the input it is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The 1-based line the problem is on.
+    pub line: usize,
+    /// The 1-based column, counted in characters, the problem starts at.
+    pub column: usize,
+    /// What the parser was looking for instead.
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Points at `at`, which must be a slice of `input`.
+    pub fn at(input: &str, at: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .expect("error location is not part of the input");
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+        }
+    }
+
+    /// Points just past the end of `at`, for when it stops too early.
+    pub fn after(input: &str, at: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at(input, &at[at.len()..], expected)
+    }
+
+    /// Renders the error under the line of `input` it points at, with a caret
+    /// marking the column.
+    pub fn annotate(&self, input: &str) -> String {
+        let text = input.lines().nth(self.line - 1).unwrap_or("");
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        format!(
+            "{gutter} --> line {}, column {}\n{gutter} |\n{} | {}\n{gutter} | {}^ expected {}",
+            self.line,
+            self.column,
+            self.line,
+            text,
+            " ".repeat(self.column - 1),
+            self.expected,
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `field`, a slice of `input`, as a `T`.
+pub fn field<T: FromStr>(input: &str, field: &str, expected: &str) -> Result<T, ParseError> {
+    field
+        .parse()
+        .map_err(|_| ParseError::at(input, field, expected))
+}
+
+/// Takes the next piece of `split`, which must come from `line`, a slice of
+/// `input`.
+pub fn next<'a>(
+    input: &str,
+    line: &'a str,
+    split: &mut impl Iterator<Item = &'a str>,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    split
+        .next()
+        .ok_or_else(|| ParseError::after(input, line, expected))
+}
+
+/// Reads every character of `line`, a slice of `input`, as a decimal digit.
+pub fn digits(input: &str, line: &str) -> Result<Vec<u32>, ParseError> {
+    line.char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .ok_or_else(|| ParseError::at(input, &line[i..], "a digit"))
+        })
+        .collect()
+}
+
+/// Strips `prefix` off `text`, a slice of `input`.
+pub fn prefix<'a>(input: &str, text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(input, text, format!("`{}`", prefix)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_location() {
+        let input = "forward 5\ndown x\n";
+        let amount = &input[15..16];
+
+        let err = field::<i32>(input, amount, "a number").unwrap_err();
+
+        assert_eq!(err.line, 2);
+        assert_eq!(err.column, 6);
+        assert_eq!(err.to_string(), "line 2, column 6: expected a number");
+    }
+
+    #[test]
+    fn test_error_after() {
+        let input = "forward 5\nup";
+        let line = input.lines().nth(1).unwrap();
+
+        let err = ParseError::after(input, line, "an amount");
+
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+
+    #[test]
+    fn test_annotate() {
+        let input = "forward 5\ndown x\n";
+        let err = ParseError::at(input, &input[15..16], "a number");
+
+        assert_eq!(
+            err.annotate(input),
+            "  --> line 2, column 6\n  |\n2 | down x\n  |      ^ expected a number"
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Works through `items` on up to `jobs` threads, each taking the next item
+/// as soon as it's free. Results are handed to `done` in the order of the
+/// items, not the order they finish in, each as soon as every item before it
+/// is done, so a single job behaves just like a plain loop.
+pub fn run_ordered<T, R>(
+    items: &[T],
+    jobs: usize,
+    work: impl Fn(&T) -> R + Sync,
+    mut done: impl FnMut(R),
+) where
+    T: Sync,
+    R: Send,
+{
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let (next, work) = (&next, &work);
+
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+
+                if sender.send((index, work(item))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut finished = BTreeMap::new();
+        let mut waiting_on = 0;
+
+        for (index, result) in receiver {
+            finished.insert(index, result);
+            while let Some(result) = finished.remove(&waiting_on) {
+                done(result);
+                waiting_on += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_run_ordered() {
+        // the first items take longest, so they finish last
+        let items: Vec<u64> = (0..8).collect();
+        let mut results = Vec::new();
+
+        run_ordered(
+            &items,
+            4,
+            |item| {
+                thread::sleep(Duration::from_millis(8 - item));
+                item * 10
+            },
+            |result| results.push(result),
+        );
+
+        assert_eq!(results, vec![0, 10, 20, 30, 40, 50, 60, 70]);
+    }
+
+    #[test]
+    fn test_run_ordered_without_items() {
+        let mut results = Vec::new();
+
+        run_ordered(&[] as &[u8], 4, |item| *item, |result| results.push(result));
+
+        assert!(results.is_empty());
+    }
+}
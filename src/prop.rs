@@ -0,0 +1,184 @@
+use std::fmt;
+
+use crate::generate::{self, Rng};
+use crate::ParseError;
+
+/// How many random inputs each property is checked against. The seeds are
+/// always the same, so a failure shows up again on the next run.
+const CASES: u64 = 200;
+
+/// An input a property didn't hold for, shrunk as small as it would go.
+#[derive(Debug)]
+pub struct Failure<T> {
+    /// The seed the input was generated from, before shrinking.
+    pub seed: u64,
+    pub input: T,
+    pub message: String,
+    /// How many times a smaller input was found that still failed.
+    pub shrinks: usize,
+}
+
+/// Checks `property` against inputs made by `generate`, returning the first
+/// one it doesn't hold for. Every input `shrink` offers that still fails is
+/// taken in its place, until none of the inputs it offers fail.
+///
+/// Inputs the property doesn't apply to, like ones the puzzle promises never
+/// to give, should pass, so shrinking can't wander onto them either.
+pub fn find_failure<T>(
+    generate: impl Fn(&mut Rng) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    property: impl Fn(&T) -> Result<(), String>,
+) -> Option<Failure<T>> {
+    let (seed, mut input, mut message) = (0..CASES).find_map(|seed| {
+        let input = generate(&mut Rng::new(seed));
+        property(&input).err().map(|message| (seed, input, message))
+    })?;
+    let mut shrinks = 0;
+
+    'shrinking: loop {
+        for candidate in shrink(&input) {
+            if let Err(candidate_message) = property(&candidate) {
+                (input, message) = (candidate, candidate_message);
+                shrinks += 1;
+                continue 'shrinking;
+            }
+        }
+
+        return Some(Failure {
+            seed,
+            input,
+            message,
+            shrinks,
+        });
+    }
+}
+
+/// Like [`find_failure`], but panics with the smallest failing input.
+pub fn check<T: fmt::Debug>(
+    generate: impl Fn(&mut Rng) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    property: impl Fn(&T) -> Result<(), String>,
+) {
+    if let Some(failure) = find_failure(generate, shrink, property) {
+        panic!(
+            "{}\nfor {:?}\n(seed {}, shrunk {} times)",
+            failure.message, failure.input, failure.seed, failure.shrinks
+        );
+    }
+}
+
+/// Makes inputs with `day`'s generator, `size` big, and parses them with
+/// `parse`, for properties to be checked against inputs like the puzzle's.
+pub fn generated<T>(
+    day: u8,
+    size: usize,
+    parse: impl Fn(&str) -> Result<T, ParseError>,
+) -> impl Fn(&mut Rng) -> T {
+    let generator = generate::get(day).expect("every day has a generator");
+
+    move |rng| {
+        let input = generator.generate(rng, size);
+        parse(&input)
+            .unwrap_or_else(|err| panic!("generated a bad input\n{}", err.annotate(&input)))
+    }
+}
+
+/// `Ok` if both implementations gave the same answer.
+pub fn agree<A: PartialEq + fmt::Debug>(naive: A, smart: A) -> Result<(), String> {
+    if naive == smart {
+        Ok(())
+    } else {
+        Err(format!(
+            "the implementations disagree: {:?} against {:?}",
+            naive, smart
+        ))
+    }
+}
+
+/// Smaller numbers to try in place of `n`, smallest first.
+pub fn shrink_number<N>(n: N) -> Vec<N>
+where
+    N: Copy + Into<u64> + TryFrom<u64>,
+{
+    let n: u64 = n.into();
+    let mut smaller = vec![0, n / 2, n.saturating_sub(1)];
+    smaller.retain(|smaller| *smaller < n);
+    smaller.dedup();
+
+    smaller
+        .into_iter()
+        .filter_map(|smaller| N::try_from(smaller).ok())
+        .collect()
+}
+
+/// Shorter lists to try in place of `items`, dropping big runs of items
+/// before single ones, followed by lists with one item shrunk by
+/// `shrink_item`.
+pub fn shrink_vec<T: Clone>(items: &[T], shrink_item: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut smaller = Vec::new();
+
+    let mut run = items.len();
+    while run > 0 {
+        for start in (0..items.len()).step_by(run) {
+            let end = (start + run).min(items.len());
+            smaller.push([&items[..start], &items[end..]].concat());
+        }
+        run /= 2;
+    }
+
+    for (i, item) in items.iter().enumerate() {
+        for shrunk in shrink_item(item) {
+            let mut items = items.to_vec();
+            items[i] = shrunk;
+            smaller.push(items);
+        }
+    }
+
+    smaller
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shrink_number() {
+        assert_eq!(shrink_number(10u32), vec![0, 5, 9]);
+        assert_eq!(shrink_number(1u32), vec![0]);
+        assert_eq!(shrink_number(0u32), vec![]);
+    }
+
+    #[test]
+    fn test_shrink_vec() {
+        let smaller = shrink_vec(&[1u32, 2, 3], |_| Vec::new());
+
+        assert_eq!(smaller, vec![vec![], vec![2, 3], vec![1, 3], vec![1, 2]]);
+    }
+
+    #[test]
+    fn test_find_failure_shrinks() {
+        let failure = find_failure(
+            |rng| (0..20).map(|_| rng.below(100) as u32).collect::<Vec<_>>(),
+            |numbers| shrink_vec(numbers, |n| shrink_number(*n)),
+            |numbers| {
+                if numbers.iter().sum::<u32>() < 100 {
+                    Ok(())
+                } else {
+                    Err(String::from("too big"))
+                }
+            },
+        )
+        .unwrap();
+
+        // nothing can be taken away or made smaller without passing
+        assert_eq!(failure.input.iter().sum::<u32>(), 100);
+        assert_eq!(failure.message, "too big");
+    }
+
+    #[test]
+    fn test_find_failure_passes() {
+        let failure = find_failure(|rng| rng.below(10), |_| Vec::new(), |_| Ok(()));
+
+        assert!(failure.is_none());
+    }
+}
@@ -0,0 +1,254 @@
+use std::io::{self, BufRead, Write};
+use std::time::Instant;
+
+use crate::days::{day11, day13, day24, day4};
+use crate::input::Source;
+use crate::{Day, ParseError, Parsed, Part};
+
+/// The commands every day has, with what each does, for `help`.
+const BUILTINS: &[(&str, &str)] = &[
+    ("part1", "answer part one"),
+    ("part2", "answer part two"),
+    ("reload", "read and parse the input again, starting over"),
+    ("help", "list the commands"),
+    ("quit", "leave"),
+];
+
+/// A day's own state to poke at between commands, for days with more to see
+/// than their answers.
+pub trait Explorer {
+    /// The day's own commands, with what each does, for `help`.
+    fn commands(&self) -> &'static [(&'static str, &'static str)];
+
+    /// Runs one of the day's [`commands`](Explorer::commands), returning what
+    /// to print.
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String>;
+}
+
+/// How a day starts exploring a parsed input.
+#[derive(Debug, Clone, Copy)]
+pub struct Commands {
+    pub day: u8,
+    explore: fn(&str) -> Result<Box<dyn Explorer>, ParseError>,
+}
+
+impl Commands {
+    /// Parses `input` and starts exploring it.
+    pub fn explore(&self, input: &str) -> Result<Box<dyn Explorer>, ParseError> {
+        (self.explore)(input)
+    }
+}
+
+pub const ALL: &[Commands] = &[
+    Commands {
+        day: 4,
+        explore: day4::explore,
+    },
+    Commands {
+        day: 11,
+        explore: day11::explore,
+    },
+    Commands {
+        day: 13,
+        explore: day13::explore,
+    },
+    Commands {
+        day: 24,
+        explore: day24::explore,
+    },
+];
+
+pub fn get(day: u8) -> Option<&'static Commands> {
+    ALL.iter().find(|commands| commands.day == day)
+}
+
+/// How many times to repeat a command like `step`, from its only argument,
+/// or once without one.
+pub(crate) fn count(args: &[&str]) -> Result<usize, String> {
+    match args {
+        [] => Ok(1),
+        [arg] => arg
+            .parse()
+            .ok()
+            .filter(|count| *count > 0)
+            .ok_or(format!("`{}` is not a positive count", arg)),
+        [_, extra, ..] => Err(format!("unexpected argument `{}`", extra)),
+    }
+}
+
+/// A day's input, parsed once and kept around to be asked about.
+pub struct Session {
+    day: Day,
+    source: Source,
+    parsed: Parsed,
+    explorer: Option<Box<dyn Explorer>>,
+}
+
+impl Session {
+    /// Reads `day`'s input from `source` and parses it.
+    pub fn start(day: Day, source: Source) -> Result<Session, String> {
+        let (parsed, explorer) = load(&day, &source)?;
+
+        Ok(Session {
+            day,
+            source,
+            parsed,
+            explorer,
+        })
+    }
+
+    pub fn prompt(&self) -> String {
+        format!("day {}> ", self.day.number)
+    }
+
+    /// Runs one line of input, returning what to print.
+    pub fn run(&mut self, line: &str) -> Result<String, String> {
+        let mut words = line.split_whitespace();
+        let Some(command) = words.next() else {
+            return Ok(String::new());
+        };
+        let args: Vec<_> = words.collect();
+
+        match command {
+            "part1" | "part2" => {
+                if let Some(extra) = args.first() {
+                    return Err(format!("unexpected argument `{}`", extra));
+                }
+                let part = if command == "part1" {
+                    Part::One
+                } else {
+                    Part::Two
+                };
+                let start = Instant::now();
+                let answer = self.day.solve(part, &self.parsed);
+                Ok(format!("{} (in {:.1?})", answer, start.elapsed()))
+            }
+            "reload" => {
+                (self.parsed, self.explorer) = load(&self.day, &self.source)?;
+                Ok(format!(
+                    "reloaded day {} from {}",
+                    self.day.number, self.source
+                ))
+            }
+            "help" => Ok(self.help()),
+            _ => match &mut self.explorer {
+                Some(explorer) if explorer.commands().iter().any(|(name, _)| *name == command) => {
+                    explorer.run(command, &args)
+                }
+                _ => Err(format!("unknown command `{}`, try `help`", command)),
+            },
+        }
+    }
+
+    fn help(&self) -> String {
+        let own = self
+            .explorer
+            .as_ref()
+            .map_or(&[][..], |explorer| explorer.commands());
+
+        own.iter()
+            .chain(BUILTINS)
+            .map(|(name, about)| format!("{:<10} {}", name, about))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn load(day: &Day, source: &Source) -> Result<(Parsed, Option<Box<dyn Explorer>>), String> {
+    source.load(day, |input| {
+        let parsed = day.parse(input)?;
+        let explorer = get(day.number)
+            .map(|commands| commands.explore(input))
+            .transpose()?;
+
+        Ok((parsed, explorer))
+    })
+}
+
+/// Reads commands from `commands` a line at a time, writing a prompt before
+/// each and the reply after, until `quit` or the end of the input. Errors
+/// are written out too, and the session carries on.
+pub fn repl(
+    session: &mut Session,
+    commands: &mut impl BufRead,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    loop {
+        write!(out, "{}", session.prompt())?;
+        out.flush()?;
+
+        let mut line = String::new();
+        if commands.read_line(&mut line)? == 0 {
+            writeln!(out)?;
+            return Ok(());
+        }
+
+        if line.trim() == "quit" {
+            return Ok(());
+        }
+        match session.run(&line) {
+            Ok(reply) if reply.is_empty() => {}
+            Ok(reply) => writeln!(out, "{}", reply)?,
+            Err(message) => writeln!(out, "error: {}", message)?,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    fn session(day: u8) -> Session {
+        Session::start(*days::get(day).unwrap(), Source::Embedded).unwrap()
+    }
+
+    #[test]
+    fn test_count() {
+        assert_eq!(count(&[]), Ok(1));
+        assert_eq!(count(&["12"]), Ok(12));
+        assert!(count(&["0"]).is_err());
+        assert!(count(&["1", "2"]).is_err());
+    }
+
+    #[test]
+    fn test_builtins() {
+        let mut session = session(1);
+
+        assert!(session.run("part1").unwrap().starts_with("1451 (in "));
+        assert!(session.run("reload").is_ok());
+        assert!(session.run("part2 now").is_err());
+        assert_eq!(session.run("  "), Ok(String::new()));
+        assert_eq!(
+            session.run("step"),
+            Err(String::from("unknown command `step`, try `help`"))
+        );
+    }
+
+    #[test]
+    fn test_help_lists_the_days_own_commands() {
+        let help = session(11).run("help").unwrap();
+
+        assert!(help.starts_with("step "));
+        assert!(help.ends_with("quit       leave"));
+    }
+
+    #[test]
+    fn test_repl() {
+        let mut out = Vec::new();
+
+        repl(
+            &mut session(11),
+            &mut "step 100\nnope\nquit\nstep\n".as_bytes(),
+            &mut out,
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "day 11> step 100: 1679 flashes so far\n\
+             day 11> error: unknown command `nope`, try `help`\n\
+             day 11> "
+        );
+    }
+}
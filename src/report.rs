@@ -0,0 +1,234 @@
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::Part;
+
+/// How answers are written out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// `Day N Part P: answer` lines, for people.
+    Text,
+    /// A JSON array with an object per answer.
+    Json,
+    /// A header line, then a line per answer.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            other => Err(format!(
+                "`{}` is not a format, expected text, json or csv",
+                other
+            )),
+        }
+    }
+}
+
+/// One part's answer, and what it took to get it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    /// Whether the part is solved. Unsolved parts are left out of the
+    /// machine readable formats.
+    pub solved: bool,
+    /// The name of the input the answer is for, see [`Source::name`].
+    ///
+    /// [`Source::name`]: crate::input::Source::name
+    pub input: String,
+    pub elapsed: Duration,
+}
+
+/// Writes records to `out` as they come in. The machine readable formats
+/// leave out parts that aren't solved yet, and need [`Report::finish`] to
+/// close them off.
+pub struct Report<W: Write> {
+    format: Format,
+    out: W,
+    written: usize,
+}
+
+impl<W: Write> Report<W> {
+    pub fn new(format: Format, out: W) -> Report<W> {
+        Report {
+            format,
+            out,
+            written: 0,
+        }
+    }
+
+    pub fn record(&mut self, record: &Record) -> io::Result<()> {
+        if self.format != Format::Text && !record.solved {
+            return Ok(());
+        }
+
+        match self.format {
+            Format::Text => writeln!(
+                self.out,
+                "Day {} Part {}: {}",
+                record.day, record.part, record.answer
+            )?,
+            Format::Json => write!(
+                self.out,
+                "{}\n  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"input\": {}, \"elapsed_ns\": {}}}",
+                if self.written == 0 { "[" } else { "," },
+                record.day,
+                record.part,
+                json_string(&record.answer),
+                json_string(&record.input),
+                record.elapsed.as_nanos(),
+            )?,
+            Format::Csv => {
+                if self.written == 0 {
+                    writeln!(self.out, "day,part,answer,input,elapsed_ns")?;
+                }
+                writeln!(
+                    self.out,
+                    "{},{},{},{},{}",
+                    record.day,
+                    record.part,
+                    csv_field(&record.answer),
+                    csv_field(&record.input),
+                    record.elapsed.as_nanos(),
+                )?
+            }
+        }
+        self.written += 1;
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        match self.format {
+            Format::Json if self.written == 0 => writeln!(self.out, "[]")?,
+            Format::Json => writeln!(self.out, "\n]")?,
+            Format::Csv if self.written == 0 => {
+                writeln!(self.out, "day,part,answer,input,elapsed_ns")?
+            }
+            _ => {}
+        }
+
+        Ok(self.out)
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut quoted = String::from("\"");
+
+    for c in text.chars() {
+        match c {
+            '"' => quoted += "\\\"",
+            '\\' => quoted += "\\\\",
+            '\n' => quoted += "\\n",
+            '\r' => quoted += "\\r",
+            '\t' => quoted += "\\t",
+            c if c.is_control() => write!(quoted, "\\u{:04x}", c as u32).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+
+    quoted
+}
+
+/// Quotes a field if it holds anything that would break up the line.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        String::from(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Unsolved;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                day: 13,
+                part: Part::Two,
+                answer: String::from("\nX.\n.X"),
+                solved: true,
+                input: String::from("embedded"),
+                elapsed: Duration::from_nanos(1500),
+            },
+            Record {
+                day: 14,
+                part: Part::Two,
+                answer: Unsolved.to_string(),
+                solved: false,
+                input: String::from("embedded"),
+                elapsed: Duration::from_nanos(20),
+            },
+            Record {
+                day: 16,
+                part: Part::One,
+                answer: String::from("891"),
+                solved: true,
+                input: String::from("alice, bob"),
+                elapsed: Duration::from_nanos(42),
+            },
+        ]
+    }
+
+    fn write_all(format: Format) -> String {
+        let mut report = Report::new(format, Vec::new());
+        for record in records() {
+            report.record(&record).unwrap();
+        }
+
+        String::from_utf8(report.finish().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            write_all(Format::Text),
+            "Day 13 Part 2: \nX.\n.X
+Day 14 Part 2: unsolved
+Day 16 Part 1: 891
+"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            write_all(Format::Json),
+            r#"[
+  {"day": 13, "part": 2, "answer": "\nX.\n.X", "input": "embedded", "elapsed_ns": 1500},
+  {"day": 16, "part": 1, "answer": "891", "input": "alice, bob", "elapsed_ns": 42}
+]
+"#
+        );
+        assert_eq!(
+            String::from_utf8(Report::new(Format::Json, Vec::new()).finish().unwrap()).unwrap(),
+            "[]\n"
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            write_all(Format::Csv),
+            "day,part,answer,input,elapsed_ns
+13,2,\"
+X.
+.X\",embedded,1500
+16,1,891,\"alice, bob\",42
+"
+        );
+    }
+}
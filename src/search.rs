@@ -0,0 +1,307 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A route found by a search, from the start node to the goal, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+/// Every node a search has reached so far, with the node it was reached from.
+struct Visited<N> {
+    nodes: Vec<N>,
+    parents: Vec<Option<usize>>,
+    indices: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash> Visited<N> {
+    fn new(start: N) -> Visited<N> {
+        Visited {
+            nodes: vec![start.clone()],
+            parents: vec![None],
+            indices: HashMap::from([(start, 0)]),
+        }
+    }
+
+    /// Records `node` as reached from `parent`, if it hasn't been reached
+    /// already, and returns its index either way.
+    fn insert(&mut self, node: N, parent: usize) -> (usize, bool) {
+        match self.indices.entry(node) {
+            Entry::Occupied(entry) => (*entry.get(), false),
+            Entry::Vacant(entry) => {
+                let index = self.nodes.len();
+                self.nodes.push(entry.key().clone());
+                self.parents.push(Some(parent));
+                entry.insert(index);
+                (index, true)
+            }
+        }
+    }
+
+    fn path_to<C>(&self, mut index: usize, cost: C) -> Path<N, C> {
+        let mut nodes = vec![self.nodes[index].clone()];
+
+        while let Some(parent) = self.parents[index] {
+            nodes.push(self.nodes[parent].clone());
+            index = parent;
+        }
+        nodes.reverse();
+
+        Path { cost, nodes }
+    }
+}
+
+/// Finds the path from `start` to a goal with the fewest steps, where every
+/// step costs one.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([(0, 0)]);
+
+    while let Some((index, steps)) = queue.pop_front() {
+        if is_goal(&visited.nodes[index]) {
+            return Some(visited.path_to(index, steps));
+        }
+
+        for next in successors(&visited.nodes[index].clone()) {
+            let (next, is_new) = visited.insert(next, index);
+            if is_new {
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds the cheapest path from `start` to a goal, where `successors` gives
+/// each node reachable from a node along with the cost of stepping to it.
+/// Costs must not be negative, and `C::default()` must be zero.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but guided towards the goal by `heuristic`, which must
+/// never overestimate the remaining cost from a node.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited = Visited::new(start);
+    let mut costs = vec![C::default()];
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&visited.nodes[0]), 0))]);
+
+    while let Some(Reverse((_, index))) = queue.pop() {
+        let node = visited.nodes[index].clone();
+        if is_goal(&node) {
+            return Some(visited.path_to(index, costs[index]));
+        }
+
+        for (next, step) in successors(&node) {
+            let cost = costs[index] + step;
+            let estimate = cost + heuristic(&next);
+            let (next, is_new) = visited.insert(next, index);
+
+            if is_new {
+                costs.push(cost);
+            } else if cost < costs[next] {
+                costs[next] = cost;
+                visited.parents[next] = Some(index);
+            } else {
+                continue;
+            }
+            queue.push(Reverse((estimate, next)));
+        }
+    }
+
+    None
+}
+
+/// Counts the distinct paths from `start` to a goal without building any of
+/// them. Paths stop at the first goal they reach. The nodes must not form a
+/// cycle, which a node can usually rule out by carrying what it has visited.
+pub fn count_paths<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> u64
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut counts = HashMap::new();
+
+    count_paths_from(&start, &mut successors, &mut is_goal, &mut counts)
+}
+
+fn count_paths_from<N, I>(
+    node: &N,
+    successors: &mut impl FnMut(&N) -> I,
+    is_goal: &mut impl FnMut(&N) -> bool,
+    counts: &mut HashMap<N, Option<u64>>,
+) -> u64
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    if is_goal(node) {
+        return 1;
+    }
+
+    match counts.get(node) {
+        Some(Some(count)) => return *count,
+        Some(None) => panic!("the nodes form a cycle, so there are endless paths"),
+        None => {}
+    }
+    counts.insert(node.clone(), None);
+
+    let count = successors(node)
+        .into_iter()
+        .map(|next| count_paths_from(&next, successors, is_goal, counts))
+        .sum();
+    counts.insert(node.clone(), Some(count));
+
+    count
+}
+
+/// How many steps it takes `state` to get where `step` says it's done, or
+/// `None` if it never does. A state that steps the same way every time has
+/// to come back round to one it's been in to go on forever, so that's what
+/// gives it away.
+///
+/// Only one earlier state is kept to look out for, moved on to every time
+/// the steps since it was kept double, so the loop is caught within a couple
+/// of times round it.
+pub fn steps_until<S: Clone + PartialEq>(
+    mut state: S,
+    mut step: impl FnMut(&mut S) -> bool,
+) -> Option<usize> {
+    let mut kept = state.clone();
+    let (mut since_kept, mut keep_for) = (0, 1);
+
+    for steps in 1.. {
+        if step(&mut state) {
+            return Some(steps);
+        }
+        if state == kept {
+            return None;
+        }
+
+        since_kept += 1;
+        if since_kept == keep_for {
+            kept = state.clone();
+            (since_kept, keep_for) = (0, keep_for * 2);
+        }
+    }
+
+    unreachable!("the steps ran out")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 - 1 - 2
+    // |       |
+    // 3 ----- 4 - 5
+    fn edges(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 10)],
+            1 => vec![(0, 1), (2, 1)],
+            2 => vec![(1, 1), (4, 1)],
+            3 => vec![(0, 10), (4, 1)],
+            4 => vec![(2, 1), (3, 1), (5, 1)],
+            _ => vec![(4, 1)],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let neighbours = |node: &u32| edges(node).into_iter().map(|(next, _)| next);
+        let path = bfs(0, neighbours, |node| *node == 5).unwrap();
+
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes, vec![0, 3, 4, 5]);
+        assert_eq!(bfs(0, neighbours, |node| *node == 6), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra(0, edges, |node| *node == 5).unwrap();
+
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.nodes, vec![0, 1, 2, 4, 5]);
+        assert_eq!(
+            dijkstra(3, edges, |node| *node == 3).unwrap().nodes,
+            vec![3]
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        let heuristic = |node: &u32| if *node == 5 { 0 } else { 1 };
+        let path = astar(0, edges, heuristic, |node| *node == 5).unwrap();
+
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.nodes, vec![0, 1, 2, 4, 5]);
+    }
+
+    #[test]
+    fn test_count_paths() {
+        // every way right and down across a 3x3 grid of cells
+        let moves = |&(x, y): &(u32, u32)| {
+            let mut next = vec![];
+            if x < 3 {
+                next.push((x + 1, y));
+            }
+            if y < 3 {
+                next.push((x, y + 1));
+            }
+            next
+        };
+
+        assert_eq!(count_paths((0, 0), moves, |pos| *pos == (3, 3)), 20);
+    }
+
+    #[test]
+    fn test_steps_until() {
+        // counting round a clock of 12 hours, from 10
+        let tick = |target| {
+            move |hour: &mut u32| {
+                *hour = (*hour + 1) % 12;
+                *hour == target
+            }
+        };
+
+        assert_eq!(steps_until(10, tick(3)), Some(5));
+        assert_eq!(steps_until(10, tick(10)), Some(12));
+        assert_eq!(steps_until(10, tick(12)), None);
+    }
+}
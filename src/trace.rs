@@ -0,0 +1,200 @@
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::RwLock;
+
+/// How much detail a message goes into, from least to most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Info = 1,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 3] = [Level::Info, Level::Debug, Level::Trace];
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Info => write!(f, "info"),
+            Level::Debug => write!(f, "debug"),
+            Level::Trace => write!(f, "trace"),
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Level, String> {
+        Level::ALL
+            .into_iter()
+            .find(|level| level.to_string() == s)
+            .ok_or(format!(
+                "`{}` is not a level, expected info, debug or trace",
+                s
+            ))
+    }
+}
+
+/// Which messages get written: the most detailed level wanted for each
+/// target, and for every other target.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    default: Option<Level>,
+    targets: Vec<(String, Level)>,
+}
+
+impl Filter {
+    pub const fn off() -> Filter {
+        Filter {
+            default: None,
+            targets: Vec::new(),
+        }
+    }
+
+    pub fn level_for(&self, target: &str) -> Option<Level> {
+        self.targets
+            .iter()
+            .rev()
+            .find(|(name, _)| name == target)
+            .map(|(_, level)| *level)
+            .or(self.default)
+    }
+
+    fn max_level(&self) -> Option<Level> {
+        self.targets
+            .iter()
+            .map(|(_, level)| *level)
+            .chain(self.default)
+            .max()
+    }
+}
+
+/// Reads a comma separated list where each entry is a level for every
+/// target, a target to trace everything from, or `target=level`. An empty
+/// filter traces everything, so `day4,day14=debug` traces all of day 4
+/// and the debug messages of day 14.
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Filter, String> {
+        let mut filter = Filter::off();
+
+        if s.is_empty() {
+            filter.default = Some(Level::Trace);
+        }
+
+        for entry in s.split(',').filter(|entry| !entry.is_empty()) {
+            match entry.split_once('=') {
+                Some((target, level)) => {
+                    filter.targets.push((String::from(target), level.parse()?))
+                }
+                None => match entry.parse() {
+                    Ok(level) => filter.default = Some(level),
+                    Err(_) => filter.targets.push((String::from(entry), Level::Trace)),
+                },
+            }
+        }
+
+        Ok(filter)
+    }
+}
+
+/// The most detailed level any target wants, so most messages can be
+/// skipped without looking at the filter.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+static FILTER: RwLock<Filter> = RwLock::new(Filter::off());
+
+/// Starts writing the messages `filter` lets through to stderr, where they
+/// can't get mixed up with answers. Nothing is written until this is called.
+pub fn init(filter: Filter) {
+    MAX_LEVEL.store(
+        filter.max_level().map_or(0, |level| level as u8),
+        Ordering::Relaxed,
+    );
+    *FILTER.write().unwrap() = filter;
+}
+
+/// The target of messages from the module at `module_path`, which is the
+/// module's own name, so `day4` for anything logged in `days::day4`.
+pub fn target(module_path: &str) -> &str {
+    module_path.rsplit("::").next().unwrap_or(module_path)
+}
+
+pub fn enabled(level: Level, target: &str) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+
+    FILTER
+        .read()
+        .unwrap()
+        .level_for(target)
+        .is_some_and(|max| level <= max)
+}
+
+pub fn write(level: Level, target: &str, message: fmt::Arguments<'_>) {
+    eprintln!("[{} {}] {}", level, target, message);
+}
+
+/// Logs a message from the current module at the given [`Level`]. The
+/// message isn't formatted unless the filter lets it through.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {{
+        let target = $crate::trace::target(module_path!());
+        if $crate::trace::enabled($level, target) {
+            $crate::trace::write($level, target, format_args!($($arg)+));
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_filter() {
+        let filter: Filter = "debug,day4,day14=info".parse().unwrap();
+
+        assert_eq!(filter.level_for("day4"), Some(Level::Trace));
+        assert_eq!(filter.level_for("day14"), Some(Level::Info));
+        assert_eq!(filter.level_for("day15"), Some(Level::Debug));
+        assert_eq!(filter.max_level(), Some(Level::Trace));
+
+        let filter: Filter = "day4=debug".parse().unwrap();
+
+        assert_eq!(filter.level_for("day4"), Some(Level::Debug));
+        assert_eq!(filter.level_for("day15"), None);
+
+        assert_eq!(
+            "".parse::<Filter>().unwrap().level_for("day1"),
+            Some(Level::Trace)
+        );
+        assert!("day4=loud".parse::<Filter>().is_err());
+    }
+
+    #[test]
+    fn test_target() {
+        assert_eq!(target("aoc2021::days::day4"), "day4");
+        assert_eq!(target("aoc2021"), "aoc2021");
+    }
+}